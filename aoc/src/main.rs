@@ -0,0 +1,347 @@
+use std::{env, fmt::Display, fs::read_to_string, path::Path, process::ExitCode, str::FromStr};
+
+use common::{
+    bench, generate, solve, solve_streaming, Answers, Generate, InputSource, Solution, Stats,
+    StreamingSolution, Timings, Verdict,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use serde::Serialize;
+
+type Solver = fn(&str, u8) -> Result<String, String>;
+type StreamingSolver = fn(&InputSource, u8) -> Result<String, String>;
+type Bencher = fn(&str, usize) -> Result<Timings, String>;
+type Generator = fn(u64, Option<usize>) -> String;
+
+struct Day {
+    number: u8,
+    solve: Solver,
+    solve_streaming: Option<StreamingSolver>,
+    bench: Bencher,
+    generate: Generator,
+}
+
+impl Day {
+    const fn new<S: Solution + Generate>(number: u8) -> Self {
+        Self {
+            number,
+            solve: solve::<S>,
+            solve_streaming: None,
+            bench: bench::<S>,
+            generate: generate::<S>,
+        }
+    }
+
+    const fn streaming<S: StreamingSolution + Generate>(number: u8) -> Self {
+        Self {
+            solve_streaming: Some(solve_streaming::<S>),
+            ..Self::new::<S>(number)
+        }
+    }
+}
+
+const DAYS: [Day; 8] = [
+    Day::streaming::<day_01::Elves>(1),
+    Day::streaming::<day_02::Game>(2),
+    Day::new::<day_03::Rucksack>(3),
+    Day::streaming::<day_04::AssignmentList>(4),
+    Day::new::<day_06::Packet>(6),
+    Day::streaming::<day_07::FileSystem>(7),
+    Day::new::<day_08::TreeGrid>(8),
+    Day::streaming::<day_09::RopeBridge>(9),
+];
+
+const USAGE: &str = "Usage:
+    aoc run --day <day> [--part <1|2>] [--input <path|->] [--answers <path>] [--stream]
+    aoc check [--day <day>] [--answers <path>]
+    aoc bench [--day <day>] [--runs <runs>] [--json]
+    aoc generate --day <day> [--seed <seed>] [--size <size>] [--marker <position>]";
+
+const DEFAULT_ANSWERS: &str = "answers.toml";
+const DEFAULT_RUNS: usize = 10;
+const DEFAULT_SEED: u64 = 2022;
+
+#[derive(Default)]
+struct Args {
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    answers: Option<String>,
+    runs: Option<usize>,
+    json: bool,
+    stream: bool,
+    seed: Option<u64>,
+    size: Option<usize>,
+    marker: Option<usize>,
+}
+
+impl Args {
+    fn from_args(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => parsed.day = Some(Self::value(&arg, args.next())?),
+                "--part" => parsed.part = Some(Self::value(&arg, args.next())?),
+                "--input" => parsed.input = Some(Self::value(&arg, args.next())?),
+                "--answers" => parsed.answers = Some(Self::value(&arg, args.next())?),
+                "--runs" => parsed.runs = Some(Self::value(&arg, args.next())?),
+                "--json" => parsed.json = true,
+                "--stream" => parsed.stream = true,
+                "--seed" => parsed.seed = Some(Self::value(&arg, args.next())?),
+                "--size" => parsed.size = Some(Self::value(&arg, args.next())?),
+                "--marker" => parsed.marker = Some(Self::value(&arg, args.next())?),
+                _ => return Err(format!("Unknown argument: {}", arg)),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    fn value<T>(arg: &str, value: Option<String>) -> Result<T, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = value.ok_or(format!("Missing value for argument: {}", arg))?;
+
+        value
+            .parse::<T>()
+            .map_err(|e| format!("Invalid value for argument {}: {}", arg, e))
+    }
+
+    fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+fn day(number: u8) -> Result<&'static Day, String> {
+    DAYS.iter()
+        .find(|day| day.number == number)
+        .ok_or(format!("Day {} is not implemented", number))
+}
+
+fn default_input(day: u8) -> String {
+    format!("day-{:02}/input.txt", day)
+}
+
+fn example_input(day: u8) -> String {
+    format!("day-{:02}/test.txt", day)
+}
+
+fn read_input(filename: &str) -> Result<String, String> {
+    read_to_string(filename).map_err(|e| format!("{}: {}", filename, e))
+}
+
+/// Prints the verdicts of `day` and tells whether any of them is a regression.
+fn report(day: u8, answers: &Answers, results: &[(u8, Result<String, String>)]) -> bool {
+    let mut regression = false;
+
+    for (part, result) in results {
+        let verdict = answers.check(day, *part, result);
+
+        match (&verdict, result) {
+            (Verdict::Pass, Ok(answer)) | (Verdict::Unknown, Ok(answer)) => {
+                println!("Day {} - Part {}: {} ({})", day, part, answer, verdict)
+            }
+            _ => println!("Day {} - Part {}: {}", day, part, verdict),
+        }
+
+        regression |= verdict.is_regression();
+    }
+
+    regression
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let day = args.day.ok_or("Missing required argument: --day")?;
+    let source = InputSource::from_arg(args.input.as_deref(), &default_input(day));
+
+    let results: Vec<_> = if args.stream {
+        let solver = self::day(day)?
+            .solve_streaming
+            .ok_or(format!("Day {} can't be solved in streaming mode", day))?;
+
+        if source == InputSource::Stdin && args.part.is_none() {
+            return Err("Streaming from stdin requires --part".to_string());
+        }
+
+        args.parts()
+            .into_iter()
+            .map(|part| (part, solver(&source, part)))
+            .collect()
+    } else {
+        let solver = self::day(day)?.solve;
+        let file_content = source.read().map_err(|e| e.to_string())?;
+
+        args.parts()
+            .into_iter()
+            .map(|part| (part, solver(&file_content, part)))
+            .collect()
+    };
+
+    let answers = match &args.answers {
+        Some(answers) => Some(Answers::from_file(answers)?),
+        None => None,
+    };
+
+    match answers {
+        Some(answers) if report(day, &answers, &results) => {
+            Err(format!("Day {} doesn't match the expected answers", day))
+        }
+        Some(_) => Ok(()),
+        None => {
+            for (part, result) in results {
+                println!("Day {} - Part {}: {}", day, part, result?);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+fn check(args: Args) -> Result<(), String> {
+    let answers = Answers::from_file(args.answers.as_deref().unwrap_or(DEFAULT_ANSWERS))?;
+
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => answers.days(),
+    };
+
+    let mut regressions = 0;
+
+    for day in days {
+        let solver = self::day(day)?.solve;
+        let file_content = read_input(&default_input(day))?;
+
+        let results: Vec<_> = args
+            .parts()
+            .into_iter()
+            .map(|part| (part, solver(&file_content, part)))
+            .collect();
+
+        if report(day, &answers, &results) {
+            regressions += 1;
+        }
+    }
+
+    match regressions {
+        0 => Ok(()),
+        _ => Err(format!(
+            "{} day(s) don't match the expected answers",
+            regressions
+        )),
+    }
+}
+
+#[derive(Serialize)]
+struct BenchReport {
+    day: u8,
+    input: String,
+    #[serde(flatten)]
+    timings: Timings,
+}
+
+fn format_ns(ns: f64) -> String {
+    match ns {
+        ns if ns >= 1e9 => format!("{:.2}s", ns / 1e9),
+        ns if ns >= 1e6 => format!("{:.2}ms", ns / 1e6),
+        ns if ns >= 1e3 => format!("{:.2}µs", ns / 1e3),
+        ns => format!("{:.0}ns", ns),
+    }
+}
+
+fn print_stats(name: &str, stats: &Stats) {
+    println!(
+        "    {:<8} mean {:>10}   median {:>10}   stddev {:>10}",
+        name,
+        format_ns(stats.mean_ns),
+        format_ns(stats.median_ns),
+        format_ns(stats.stddev_ns)
+    );
+}
+
+fn bench_days(args: Args) -> Result<(), String> {
+    let days: Vec<&Day> = match args.day {
+        Some(number) => vec![day(number)?],
+        None => DAYS.iter().collect(),
+    };
+    let runs = args.runs.unwrap_or(DEFAULT_RUNS);
+
+    let mut reports = vec![];
+
+    for day in days {
+        let filenames = [default_input(day.number), example_input(day.number)];
+
+        for filename in filenames.into_iter().filter(|f| Path::new(f).exists()) {
+            let file_content = read_input(&filename)?;
+            let timings = (day.bench)(&file_content, runs)?;
+
+            if !args.json {
+                println!("Day {} ({}, {} runs)", day.number, filename, runs);
+                print_stats("parse", &timings.parse);
+                print_stats("part 1", &timings.part_1);
+                print_stats("part 2", &timings.part_2);
+            }
+
+            reports.push(BenchReport {
+                day: day.number,
+                input: filename,
+                timings,
+            });
+        }
+    }
+
+    if args.json {
+        let json = serde_json::to_string_pretty(&reports).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    }
+
+    Ok(())
+}
+
+fn generate_input(args: Args) -> Result<(), String> {
+    let day = args.day.ok_or("Missing required argument: --day")?;
+    let seed = args.seed.unwrap_or(DEFAULT_SEED);
+
+    let input = match (day, args.marker) {
+        (6, Some(marker)) => {
+            let size = args.size.unwrap_or(day_06::Packet::DEFAULT_SIZE);
+
+            if !(4..size).contains(&marker) {
+                return Err(format!("The marker must be in 4..{}", size));
+            }
+
+            let mut rng = StdRng::seed_from_u64(seed);
+            day_06::generate_with_marker(&mut rng, size, marker)
+        }
+        (_, Some(_)) => return Err("--marker is only supported by day 6".to_string()),
+        (_, None) => (self::day(day)?.generate)(seed, args.size),
+    };
+
+    print!("{}", input);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+
+    let result = match args.next().as_deref() {
+        Some("run") => Args::from_args(args).and_then(run),
+        Some("check") => Args::from_args(args).and_then(check),
+        Some("bench") => Args::from_args(args).and_then(bench_days),
+        Some("generate") => Args::from_args(args).and_then(generate_input),
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::{collections::HashMap, fmt::Display, fs::read_to_string, str::FromStr};
+
+use toml::{Table, Value};
+
+/// Expected answers read from a TOML file with a table per day and a key per part:
+///
+/// ```toml
+/// [7]
+/// 1 = 1390824
+/// 2 = 7490863
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: HashMap<(u8, u8), String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Mismatch { expected: String, actual: String },
+    Fail(String),
+    Unknown,
+}
+
+impl Answers {
+    pub fn from_file(filename: &str) -> Result<Self, String> {
+        let file_content = read_to_string(filename).map_err(|e| format!("{}: {}", filename, e))?;
+
+        file_content
+            .parse()
+            .map_err(|e| format!("{}: {}", filename, e))
+    }
+
+    pub fn expected(&self, day: u8, part: u8) -> Option<&str> {
+        self.expected
+            .get(&(day, part))
+            .map(|answer| answer.as_str())
+    }
+
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self.expected.keys().map(|(day, _)| *day).collect();
+
+        days.sort();
+        days.dedup();
+
+        days
+    }
+
+    /// Compares the result of solving `part` of `day` with its expected answer.
+    pub fn check(&self, day: u8, part: u8, result: &Result<String, String>) -> Verdict {
+        match (self.expected(day, part), result) {
+            (_, Err(err)) => Verdict::Fail(err.to_owned()),
+            (None, Ok(_)) => Verdict::Unknown,
+            (Some(expected), Ok(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), Ok(actual)) => Verdict::Mismatch {
+                expected: expected.to_owned(),
+                actual: actual.to_owned(),
+            },
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table: Table = s.parse().map_err(|e: toml::de::Error| e.to_string())?;
+        let mut expected = HashMap::new();
+
+        for (day_key, parts) in table {
+            let day = day_key
+                .parse::<u8>()
+                .map_err(|_| format!("Expected a day number, found `{}`", day_key))?;
+            let parts = parts
+                .as_table()
+                .ok_or(format!("Expected a table of parts for day {}", day))?;
+
+            for (part_key, answer) in parts {
+                let part = part_key
+                    .parse::<u8>()
+                    .map_err(|_| format!("Expected a part number, found `{}`", part_key))?;
+
+                let answer = match answer {
+                    Value::String(answer) => answer.to_owned(),
+                    Value::Integer(answer) => answer.to_string(),
+                    _ => return Err(format!("Unexpected answer for day {} part {}", day, part)),
+                };
+
+                expected.insert((day, part), answer);
+            }
+        }
+
+        Ok(Self { expected })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Mismatch { expected, actual } => {
+                write!(f, "mismatch: expected {}, got {}", expected, actual)
+            }
+            Verdict::Fail(err) => write!(f, "fail: {}", err),
+            Verdict::Unknown => write!(f, "no expected answer"),
+        }
+    }
+}
+
+impl Verdict {
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Verdict::Mismatch { .. } | Verdict::Fail(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "[7]\n1 = 95437\n2 = \"24933642\"\n";
+
+    #[test]
+    fn parse_answers() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+
+        assert_eq!(answers.expected(7, 1), Some("95437"));
+        assert_eq!(answers.expected(7, 2), Some("24933642"));
+        assert_eq!(answers.expected(1, 1), None);
+    }
+
+    #[test]
+    fn check_answers() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+
+        assert_eq!(answers.check(7, 1, &Ok("95437".into())), Verdict::Pass);
+        assert_eq!(
+            answers.check(7, 2, &Ok("1".into())),
+            Verdict::Mismatch {
+                expected: "24933642".into(),
+                actual: "1".into()
+            }
+        );
+        assert_eq!(answers.check(8, 1, &Ok("1".into())), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::Solution;
+
+/// Summary of the durations of several runs, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub stddev_ns: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part_1: Stats,
+    pub part_2: Stats,
+}
+
+impl Stats {
+    pub fn from_durations(durations: &[Duration]) -> Self {
+        let mut nanos: Vec<f64> = durations.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(|a, b| a.total_cmp(b));
+
+        let runs = nanos.len();
+        let mean_ns = nanos.iter().sum::<f64>() / runs.max(1) as f64;
+
+        let median_ns = match runs {
+            0 => 0.0,
+            n if n % 2 == 0 => (nanos[n / 2 - 1] + nanos[n / 2]) / 2.0,
+            n => nanos[n / 2],
+        };
+
+        let variance =
+            nanos.iter().map(|n| (n - mean_ns).powi(2)).sum::<f64>() / runs.max(1) as f64;
+
+        Self {
+            runs,
+            mean_ns,
+            median_ns,
+            stddev_ns: variance.sqrt(),
+        }
+    }
+}
+
+fn time_runs<T>(runs: usize, mut f: impl FnMut() -> T) -> Stats {
+    // A first run to warm up caches and the thread pools
+    black_box(f());
+
+    let durations: Vec<Duration> = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_durations(&durations)
+}
+
+/// Times parsing, part 1 and part 2 of `S` separately, each over `runs` runs.
+pub fn bench<S: Solution>(input: &str, runs: usize) -> Result<Timings, String> {
+    let parsed = S::parse(input).map_err(|err| err.to_string())?;
+
+    let parse = time_runs(runs, || S::parse(black_box(input)));
+    let part_1 = time_runs(runs, || S::part_1(black_box(&parsed)));
+    let part_2 = time_runs(runs, || S::part_2(black_box(&parsed)));
+
+    Ok(Timings {
+        parse,
+        part_1,
+        part_2,
+    })
+}
+
+/// Registers criterion benchmarks of parsing and both parts of `S` on `input` and `test`.
+#[cfg(feature = "criterion")]
+pub fn criterion_bench<S: Solution>(
+    c: &mut criterion::Criterion,
+    day: &str,
+    input: &str,
+    test: &str,
+) {
+    for (name, content) in [("input", input), ("test", test)] {
+        let parsed =
+            S::parse(content).unwrap_or_else(|err| panic!("The {} should be valid: {}", name, err));
+
+        c.bench_function(&format!("{} {} parse", day, name), |b| {
+            b.iter(|| S::parse(black_box(content)))
+        });
+        c.bench_function(&format!("{} {} part 1", day, name), |b| {
+            b.iter(|| S::part_1(black_box(&parsed)))
+        });
+        c.bench_function(&format!("{} {} part 2", day, name), |b| {
+            b.iter(|| S::part_2(black_box(&parsed)))
+        });
+    }
+}
+
+/// The criterion `main` of a day, benchmarking `$solution` on the `input.txt` and
+/// `test.txt` of the crate.
+#[cfg(feature = "criterion")]
+#[macro_export]
+macro_rules! criterion_benches {
+    ($solution:ty) => {
+        fn solution(c: &mut $crate::criterion::Criterion) {
+            $crate::criterion_bench::<$solution>(
+                c,
+                &env!("CARGO_PKG_NAME").replace('-', " "),
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")),
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/test.txt")),
+            );
+        }
+
+        $crate::criterion::criterion_group!(benches, solution);
+        $crate::criterion::criterion_main!(benches);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_durations() {
+        let durations: Vec<_> = [4, 2, 8, 6].into_iter().map(Duration::from_nanos).collect();
+        let stats = Stats::from_durations(&durations);
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.mean_ns, 5.0);
+        assert_eq!(stats.median_ns, 5.0);
+        assert_eq!(stats.stddev_ns, 5.0_f64.sqrt());
+    }
+}
@@ -0,0 +1,25 @@
+use crate::Solution;
+
+/// An example from the puzzle description together with its known answers.
+pub struct Example {
+    pub input: &'static str,
+    pub part_1: Option<&'static str>,
+    pub part_2: Option<&'static str>,
+}
+
+impl Example {
+    /// Panics if `S` doesn't parse the example or doesn't find its answers.
+    pub fn assert_solved_by<S: Solution>(&self) {
+        let parsed = S::parse(self.input).unwrap_or_else(|err| panic!("{}", err));
+
+        if let Some(expected) = self.part_1 {
+            let answer = S::part_1(&parsed).map(|answer| answer.to_string());
+            assert_eq!(answer.as_deref(), Some(expected), "part 1");
+        }
+
+        if let Some(expected) = self.part_2 {
+            let answer = S::part_2(&parsed).map(|answer| answer.to_string());
+            assert_eq!(answer.as_deref(), Some(expected), "part 2");
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A day whose puzzle inputs can be generated at random, to stress-test and benchmark it.
+pub trait Generate {
+    /// The size used when none is given.
+    const DEFAULT_SIZE: usize;
+
+    /// A random valid input, `size` being a day-specific measure of its length.
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String;
+}
+
+/// The input generated by `G` from `seed`: the same seed always gives the same input.
+pub fn generate<G: Generate>(seed: u64, size: Option<usize>) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    G::generate(&mut rng, size.unwrap_or(G::DEFAULT_SIZE))
+}
@@ -0,0 +1,140 @@
+use std::{
+    env,
+    fmt::Display,
+    fs::read_to_string,
+    fs::File,
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::PathBuf,
+};
+
+/// Environment variable holding the whole puzzle input inline.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    /// `-` reads from stdin and anything else is a path. Without an argument the input is
+    /// taken from the `AOC_INPUT` environment variable if set, or from `default` otherwise.
+    pub fn from_arg(arg: Option<&str>, default: &str) -> Self {
+        match arg {
+            Some("-") => Self::Stdin,
+            Some(path) => Self::File(PathBuf::from(path)),
+            None => match env::var(INPUT_ENV) {
+                Ok(content) => Self::Inline(content),
+                Err(_) => Self::File(PathBuf::from(default)),
+            },
+        }
+    }
+
+    /// Same as [`InputSource::from_arg`] with the first argument of the command line.
+    pub fn from_args(default: &str) -> Self {
+        Self::from_arg(env::args().nth(1).as_deref(), default)
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Self::File(path) => read_to_string(path)
+                .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", self, err))),
+            Self::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content)?;
+
+                Ok(content)
+            }
+            Self::Inline(content) => Ok(content.to_owned()),
+        }
+    }
+
+    /// A buffered reader over the input, to process it without loading it all in memory.
+    pub fn reader(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Self::File(path) => {
+                let file = File::open(path)
+                    .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", self, err)))?;
+
+                Ok(Box::new(BufReader::new(file)))
+            }
+            Self::Stdin => Ok(Box::new(io::stdin().lock())),
+            Self::Inline(content) => Ok(Box::new(Cursor::new(content.to_owned()))),
+        }
+    }
+}
+
+/// Removes `flag` from `args` and tells whether it was there.
+pub fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    match args.iter().position(|arg| arg == flag) {
+        Some(idx) => {
+            args.remove(idx);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Removes `flag` and its value from `args` and returns the value.
+pub fn take_value(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, String> {
+    match args.iter().position(|arg| arg == flag) {
+        Some(idx) if idx + 1 < args.len() => {
+            let value = args.remove(idx + 1);
+            args.remove(idx);
+
+            Ok(Some(value))
+        }
+        Some(_) => Err(format!("Missing value for argument: {}", flag)),
+        None => Ok(None),
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::Inline(_) => write!(f, "${}", INPUT_ENV),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_from_arg() {
+        assert_eq!(
+            InputSource::from_arg(Some("-"), "input.txt"),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::from_arg(Some("test.txt"), "input.txt"),
+            InputSource::File(PathBuf::from("test.txt"))
+        );
+    }
+
+    #[test]
+    fn read_inline() {
+        let source = InputSource::Inline(String::from("A Y"));
+
+        assert_eq!(source.read().unwrap(), "A Y");
+    }
+
+    #[test]
+    fn take_flags_and_values() {
+        let mut args: Vec<String> = ["input.txt", "--rpsls", "--part", "2", "--export"]
+            .map(String::from)
+            .to_vec();
+
+        assert!(take_flag(&mut args, "--rpsls"));
+        assert!(!take_flag(&mut args, "--rpsls"));
+        assert_eq!(take_value(&mut args, "--part"), Ok(Some(String::from("2"))));
+        assert_eq!(take_value(&mut args, "--rules"), Ok(None));
+        assert!(take_value(&mut args, "--export").is_err());
+        assert_eq!(args, ["input.txt", "--export"]);
+    }
+}
@@ -0,0 +1,20 @@
+mod answers;
+mod bench;
+mod example;
+mod generate;
+mod input;
+mod parse_error;
+mod solution;
+
+pub use answers::{Answers, Verdict};
+#[cfg(feature = "criterion")]
+pub use bench::criterion_bench;
+pub use bench::{bench, Stats, Timings};
+#[cfg(feature = "criterion")]
+#[doc(hidden)]
+pub use criterion;
+pub use example::Example;
+pub use generate::{generate, Generate};
+pub use input::{take_flag, take_value, InputSource, INPUT_ENV};
+pub use parse_error::{parse_lines, parse_lines_from, ParseError};
+pub use solution::{solve, solve_streaming, Solution, StreamingSolution};
@@ -0,0 +1,249 @@
+use std::{error::Error, fmt::Display, io::BufRead, ops::Range, str::FromStr};
+
+/// An error raised while parsing a puzzle input.
+///
+/// Besides what was expected, it can carry the offending token, its column span and
+/// the line it was found on, which are rendered as a caret-underlined snippet:
+///
+/// ```text
+/// expected a section number, found `x`
+///  --> line 3, column 3
+///   |
+/// 3 | 2-x,4-5
+///   |   ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    expected: String,
+    token: Option<String>,
+    columns: Range<usize>,
+    line: Option<(usize, String)>,
+}
+
+impl ParseError {
+    /// An error without any location, only describing what went wrong.
+    pub fn new(message: &str) -> Self {
+        Self {
+            expected: message.to_string(),
+            token: None,
+            columns: 0..0,
+            line: None,
+        }
+    }
+
+    /// `token` was found at `columns` (byte offsets) where `expected` was expected.
+    pub fn unexpected(token: &str, columns: Range<usize>, expected: &str) -> Self {
+        Self {
+            expected: expected.to_string(),
+            token: Some(token.to_string()),
+            columns,
+            line: None,
+        }
+    }
+
+    /// `token`, a slice of `s`, is not what `expected` describes.
+    pub fn unexpected_token(s: &str, token: &str, expected: &str) -> Self {
+        let start = offset_of(s, token);
+
+        Self::unexpected(token, start..(start + token.len()), expected)
+    }
+
+    /// `s` ended while `expected` was still expected.
+    pub fn end_of_input(s: &str, expected: &str) -> Self {
+        Self::unexpected("", s.len()..s.len(), expected)
+    }
+
+    /// Moves an error raised while parsing `part`, a slice of `s`, to the columns of `s`.
+    pub fn within(mut self, s: &str, part: &str) -> Self {
+        let offset = offset_of(s, part);
+        self.columns = (self.columns.start + offset)..(self.columns.end + offset);
+
+        self
+    }
+
+    /// Attaches the 1-based line number and the content of the line where the error was found.
+    pub fn at_line(mut self, number: usize, line: &str) -> Self {
+        if self.line.is_none() {
+            self.line = Some((number, line.to_string()));
+        }
+
+        self
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    pub fn columns(&self) -> Range<usize> {
+        self.columns.clone()
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line.as_ref().map(|(number, _)| *number)
+    }
+}
+
+/// Parses every line of `s`, attaching the line number to the first error found.
+pub fn parse_lines<T>(s: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    s.lines()
+        .enumerate()
+        .map(|(idx, line)| line.parse::<T>().map_err(|err| err.at_line(idx + 1, line)))
+        .collect()
+}
+
+/// Streaming version of [`parse_lines`], reading one line at a time from `reader`.
+pub fn parse_lines_from<T, R>(reader: R) -> impl Iterator<Item = Result<T, ParseError>>
+where
+    T: FromStr<Err = ParseError>,
+    R: BufRead,
+{
+    reader.lines().enumerate().map(|(idx, line)| {
+        let line = line?;
+        line.parse::<T>().map_err(|err| err.at_line(idx + 1, &line))
+    })
+}
+
+/// Byte offset of `part` inside `s`, if `part` was sliced from `s`.
+fn offset_of(s: &str, part: &str) -> usize {
+    let start = s.as_ptr() as usize;
+    let part_start = part.as_ptr() as usize;
+
+    if part_start >= start && part_start + part.len() <= start + s.len() {
+        part_start - start
+    } else {
+        s.find(part).unwrap_or(0)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.token {
+            None => write!(f, "{}", self.expected)?,
+            Some(token) if token.is_empty() => {
+                write!(f, "expected {}, found end of line", self.expected)?
+            }
+            Some(token) => write!(f, "expected {}, found `{}`", self.expected, token)?,
+        }
+
+        let (number, line) = match (&self.token, &self.line) {
+            (Some(_), Some((number, line))) => (number, line),
+            (None, Some((number, _))) => return write!(f, " (line {})", number),
+            (_, None) => return Ok(()),
+        };
+
+        let start = line
+            .get(..self.columns.start)
+            .unwrap_or(line)
+            .chars()
+            .count();
+        let width = line
+            .get(self.columns.clone())
+            .map(|token| token.chars().count())
+            .unwrap_or(0)
+            .max(1);
+        let gutter = " ".repeat(number.to_string().len());
+
+        writeln!(f)?;
+        writeln!(f, "{}--> line {}, column {}", gutter, number, start + 1)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, line)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(start), "^".repeat(width))
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<String> for ParseError {
+    fn from(message: String) -> Self {
+        Self::new(&message)
+    }
+}
+
+impl From<&str> for ParseError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<std::io::Error> for ParseError {
+    fn from(err: std::io::Error) -> Self {
+        Self::new(&err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unexpected_token_columns() {
+        let line = "2-x,4-5";
+        let err = ParseError::unexpected_token(line, &line[2..3], "a section number");
+
+        assert_eq!(err.columns(), 2..3);
+        assert_eq!(err.token(), Some("x"));
+    }
+
+    #[test]
+    fn within_shifts_columns() {
+        let line = "2-4,6-x";
+        let (_, second) = line.split_once(',').unwrap();
+        let err = ParseError::unexpected_token(second, &second[2..], "a section number")
+            .within(line, second);
+
+        assert_eq!(err.columns(), 6..7);
+    }
+
+    #[test]
+    fn render_snippet() {
+        let err = ParseError::unexpected("x", 2..3, "a section number").at_line(3, "2-x,4-5");
+
+        assert_eq!(
+            err.to_string(),
+            "expected a section number, found `x`\n --> line 3, column 3\n  |\n3 | 2-x,4-5\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn render_end_of_line() {
+        let err = ParseError::end_of_input("2-4", "`,`").at_line(12, "2-4");
+
+        assert_eq!(
+            err.to_string(),
+            "expected `,`, found end of line\n  --> line 12, column 4\n   |\n12 | 2-4\n   |    ^"
+        );
+    }
+
+    #[test]
+    fn parse_lines_attaches_line_number() {
+        #[derive(Debug)]
+        struct Digit;
+
+        impl FromStr for Digit {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    "1" => Ok(Digit),
+                    _ => Err(ParseError::unexpected_token(s, s, "`1`")),
+                }
+            }
+        }
+
+        let err = parse_lines::<Digit>("1\n1\n2").unwrap_err();
+
+        assert_eq!(err.line(), Some(3));
+
+        let results: Vec<_> = parse_lines_from::<Digit, _>("1\n2\n1".as_bytes()).collect();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[1].as_ref().unwrap_err().line(), Some(2));
+    }
+}
@@ -0,0 +1,54 @@
+use std::{fmt::Display, io::BufRead};
+
+use crate::{InputSource, ParseError};
+
+/// A day of the calendar: how to parse its input and how to solve its two parts.
+pub trait Solution {
+    type Input;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_1(input: &Self::Input) -> Option<Self::Answer>;
+
+    fn part_2(input: &Self::Input) -> Option<Self::Answer>;
+}
+
+/// A day that can also be solved reading its input one line at a time, in bounded memory.
+pub trait StreamingSolution: Solution {
+    fn part_1_from_reader<R: BufRead>(reader: R) -> Result<Option<Self::Answer>, ParseError>;
+
+    fn part_2_from_reader<R: BufRead>(reader: R) -> Result<Option<Self::Answer>, ParseError>;
+}
+
+pub fn solve<S: Solution>(input: &str, part: u8) -> Result<String, String> {
+    let parsed = S::parse(input).map_err(|err| err.to_string())?;
+
+    let answer = match part {
+        1 => S::part_1(&parsed),
+        2 => S::part_2(&parsed),
+        _ => return Err(format!("Unknown part: {}", part)),
+    };
+
+    answer
+        .map(|answer| answer.to_string())
+        .ok_or(format!("Can't find an answer for part {}", part))
+}
+
+pub fn solve_streaming<S: StreamingSolution>(
+    source: &InputSource,
+    part: u8,
+) -> Result<String, String> {
+    let reader = source.reader().map_err(|err| err.to_string())?;
+
+    let answer = match part {
+        1 => S::part_1_from_reader(reader),
+        2 => S::part_2_from_reader(reader),
+        _ => return Err(format!("Unknown part: {}", part)),
+    }
+    .map_err(|err| err.to_string())?;
+
+    answer
+        .map(|answer| answer.to_string())
+        .ok_or(format!("Can't find an answer for part {}", part))
+}
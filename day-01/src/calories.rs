@@ -0,0 +1,24 @@
+use std::fmt::Display;
+
+/// An unsigned integer wide enough to add up the calories of an elf.
+pub trait Calories: Copy + Ord + Display + From<u32> {
+    const NAME: &'static str;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_calories {
+    ($($t:ty),*) => {
+        $(
+            impl Calories for $t {
+                const NAME: &'static str = stringify!($t);
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_calories!(u32, u64, u128);
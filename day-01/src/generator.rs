@@ -0,0 +1,42 @@
+use rand::Rng;
+
+use common::Generate;
+
+use crate::Elves;
+
+impl Generate for Elves {
+    const DEFAULT_SIZE: usize = 250;
+
+    /// `size` elves, each carrying a few items.
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        let elves: Vec<String> = (0..size)
+            .map(|_| {
+                let items_count = rng.gen_range(1..=15);
+
+                (0..items_count)
+                    .map(|_| rng.gen_range(1000..=60000).to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect();
+
+        elves.join("\n\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn generated_input_is_valid() {
+        let input = generate::<Elves>(42, Some(10));
+        let elves = Elves::parse(&input).unwrap();
+
+        assert_eq!(input, generate::<Elves>(42, Some(10)));
+        assert!(Elves::part_1(&elves).is_some());
+        assert!(Elves::part_2(&elves).is_some());
+    }
+}
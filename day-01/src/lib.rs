@@ -0,0 +1,280 @@
+use std::{cmp::Reverse, collections::BTreeSet};
+
+use common::{Example, ParseError, Solution};
+
+mod calories;
+mod generator;
+mod rebalance;
+mod stats;
+mod stream;
+mod top;
+mod update;
+
+pub use crate::calories::Calories;
+pub use crate::rebalance::{Move, Plan, EXACT_MAX_ITEMS};
+pub use crate::stats::{Bucket, ElfStats};
+pub use crate::stream::ElfTotals;
+pub use crate::top::TopN;
+
+/// The calories of the items carried by each elf.
+#[derive(Debug)]
+pub struct Elves {
+    weights: Vec<Vec<u32>>,
+    // Line of the first item of each elf in the parsed input, to locate overflows
+    lines: Vec<usize>,
+    totals: Vec<u64>,
+    // Elves from the highest total to the lowest, the first one winning ties
+    ranking: BTreeSet<(Reverse<u64>, usize)>,
+}
+
+impl Elves {
+    /// Elves whose items are laid out as in the puzzle input, separated by a blank line.
+    /// Fails if the calories carried by an elf overflow a `u64`.
+    pub fn new(weights: Vec<Vec<u32>>) -> Result<Self, ParseError> {
+        let lines = weights
+            .iter()
+            .scan(1, |line, items| {
+                let first = *line;
+                *line += items.len() + 1;
+
+                Some(first)
+            })
+            .collect();
+
+        Self::from_parts(weights, lines)
+    }
+
+    fn from_parts(weights: Vec<Vec<u32>>, lines: Vec<usize>) -> Result<Self, ParseError> {
+        let totals: Vec<u64> = weights
+            .iter()
+            .zip(&lines)
+            .enumerate()
+            .map(|(elf, (items, &first_line))| checked_total(elf, items, first_line))
+            .collect::<Result<_, _>>()?;
+        let ranking = totals
+            .iter()
+            .enumerate()
+            .map(|(elf, &total)| (Reverse(total), elf))
+            .collect();
+
+        Ok(Self {
+            weights,
+            lines,
+            totals,
+            ranking,
+        })
+    }
+
+    /// Total calories carried by each elf, in input order, added up with overflow checks
+    /// when the elves were built.
+    pub fn totals(&self) -> impl Iterator<Item = u64> + '_ {
+        self.totals.iter().copied()
+    }
+
+    /// Same as [`Elves::totals`] with any accumulator, failing on the item that overflows it.
+    pub fn checked_totals<C: Calories>(&self) -> Result<Vec<C>, ParseError> {
+        self.weights
+            .iter()
+            .zip(&self.lines)
+            .enumerate()
+            .map(|(elf, (items, &first_line))| checked_total(elf, items, first_line))
+            .collect()
+    }
+
+    /// Part 1 with a custom accumulator.
+    pub fn max_total<C: Calories>(&self) -> Result<Option<C>, ParseError> {
+        Ok(self.checked_totals::<C>()?.into_iter().max())
+    }
+
+    /// The sum of the `n` highest totals with a custom accumulator, if there are `n` elves.
+    pub fn top_total<C: Calories>(&self, n: usize) -> Result<Option<C>, ParseError> {
+        let top = self.top_n(n);
+
+        if top.len() < n {
+            return Ok(None);
+        }
+
+        let totals = self.checked_totals::<C>()?;
+
+        top.iter()
+            .try_fold(C::from(0), |sum, &(elf, _)| {
+                sum.checked_add(totals[elf]).ok_or_else(|| {
+                    ParseError::new(&format!(
+                        "the sum of the top {} elves overflows a {}",
+                        n,
+                        C::NAME
+                    ))
+                })
+            })
+            .map(Some)
+    }
+
+    /// The index and total of the `n` elves carrying the most calories, from the most
+    /// to the least. There are fewer than `n` of them when there are fewer elves.
+    pub fn top_n(&self, n: usize) -> Vec<(usize, u64)> {
+        self.ranking
+            .iter()
+            .take(n)
+            .map(|&(Reverse(total), elf)| (elf, total))
+            .collect()
+    }
+}
+
+/// The calories carried by `elf`, failing on the item that overflows `C`.
+pub(crate) fn checked_total<C: Calories>(
+    elf: usize,
+    items: &[u32],
+    first_line: usize,
+) -> Result<C, ParseError> {
+    items
+        .iter()
+        .enumerate()
+        .try_fold(C::from(0), |total, (idx, &weight)| {
+            total
+                .checked_add(C::from(weight))
+                .ok_or_else(|| overflow::<C>(elf).at_line(first_line + idx, &weight.to_string()))
+        })
+}
+
+/// The sum of the totals of the `top` elves, failing if it overflows a `u64`.
+pub(crate) fn top_sum(top: &[(usize, u64)]) -> Result<u64, ParseError> {
+    top.iter()
+        .try_fold(0u64, |sum, &(_, total)| sum.checked_add(total))
+        .ok_or_else(|| {
+            ParseError::new(&format!(
+                "the sum of the top {} elves overflows a u64",
+                top.len()
+            ))
+        })
+}
+
+pub(crate) fn overflow<C: Calories>(elf: usize) -> ParseError {
+    ParseError::new(&format!(
+        "the calories carried by elf {} overflow a {}",
+        elf + 1,
+        C::NAME
+    ))
+}
+
+/// Parses the items of each elf, one per line, with a blank line between two elves.
+/// Trailing whitespace and Windows line endings are accepted.
+pub fn weights_by_elf(contents: &str) -> Result<Elves, ParseError> {
+    let mut weights: Vec<Vec<u32>> = vec![];
+    let mut lines = vec![];
+    let mut in_group = false;
+
+    for (idx, line) in contents.lines().enumerate() {
+        let weight = line.trim();
+
+        if weight.is_empty() {
+            in_group = false;
+            continue;
+        }
+
+        if !in_group {
+            weights.push(vec![]);
+            lines.push(idx + 1);
+            in_group = true;
+        }
+
+        let expected = format!("a number of calories for elf {}", weights.len());
+        let weight = weight.parse::<u32>().map_err(|_| {
+            ParseError::unexpected_token(line, weight, &expected).at_line(idx + 1, line)
+        })?;
+
+        weights.last_mut().unwrap().push(weight);
+    }
+
+    Elves::from_parts(weights, lines)
+}
+
+pub fn part_1(elves: &Elves) -> Option<u64> {
+    elves.top_n(1).first().map(|&(_, total)| total)
+}
+
+pub fn part_2(elves: &Elves) -> Option<u64> {
+    let top = elves.top_n(3);
+
+    match top.len() {
+        3 => top_sum(&top).ok(),
+        _ => None,
+    }
+}
+
+impl Solution for Elves {
+    type Input = Elves;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        weights_by_elf(input)
+    }
+
+    fn part_1(elves: &Self::Input) -> Option<Self::Answer> {
+        part_1(elves)
+    }
+
+    fn part_2(elves: &Self::Input) -> Option<Self::Answer> {
+        part_2(elves)
+    }
+}
+
+/// The examples of the puzzle description.
+pub const EXAMPLES: [Example; 1] = [Example {
+    input: include_str!("../test.txt"),
+    part_1: Some("24000"),
+    part_2: Some("45000"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        for example in EXAMPLES {
+            example.assert_solved_by::<Elves>();
+        }
+    }
+
+    #[test]
+    fn top_n() {
+        let elves = weights_by_elf(EXAMPLES[0].input).unwrap();
+
+        assert_eq!(elves.top_n(1), vec![(3, 24000)]);
+        assert_eq!(elves.top_n(3), vec![(3, 24000), (2, 11000), (4, 10000)]);
+        assert_eq!(elves.top_n(100).len(), 5);
+        assert_eq!(elves.top_n(usize::MAX).len(), 5);
+        assert_eq!(part_2(&weights_by_elf("1000\n\n2000").unwrap()), None);
+    }
+
+    #[test]
+    fn checked_accumulators() {
+        let elves = weights_by_elf("1\n\n4000000000\n300000000\n").unwrap();
+        let err = elves.max_total::<u32>().unwrap_err();
+
+        assert_eq!(err.line(), Some(4));
+        assert!(err.to_string().contains("elf 2 overflow a u32"));
+        assert_eq!(elves.max_total::<u64>(), Ok(Some(4300000000)));
+        assert_eq!(elves.top_total::<u128>(2), Ok(Some(4300000001)));
+        assert_eq!(elves.top_total::<u128>(3), Ok(None));
+        assert_eq!(part_1(&elves), Some(4300000000));
+
+        let err = top_sum(&[(0, u64::MAX), (1, 1), (2, 0)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the sum of the top 3 elves overflows a u64"
+        );
+    }
+
+    #[test]
+    fn parse_errors_and_line_endings() {
+        let elves = weights_by_elf("1000 \r\n2000\r\n \r\n\r\n3000\r\n").unwrap();
+
+        assert_eq!(elves.totals().collect::<Vec<_>>(), vec![3000, 3000]);
+
+        let err = weights_by_elf("1000\n\n2000\n2x00").unwrap_err();
+
+        assert_eq!(err.line(), Some(4));
+        assert_eq!(err.expected(), "a number of calories for elf 2");
+    }
+}
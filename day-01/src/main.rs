@@ -1,52 +1,78 @@
-use std::fs::read_to_string;
-use std::io::Error;
+use std::env;
 
-struct Elves {
-    weights: Vec<Vec<u32>>,
-}
+use common::{take_flag, InputSource};
+use day_01::{part_1, part_2, weights_by_elf, Elves};
+
+const REPORT_FLAG: &str = "--report";
+const HISTOGRAM_BUCKETS: usize = 10;
+const HISTOGRAM_WIDTH: usize = 40;
+
+fn print_report(elves: &Elves) {
+    let outliers: Vec<usize> = elves.outliers().iter().map(|elf| elf.index).collect();
+
+    println!("{:>6} {:>6} {:>10}", "elf", "items", "calories");
 
-impl Elves {
-    fn new(weights: Vec<Vec<u32>>) -> Self {
-        Self { weights }
+    for elf in elves.elf_stats() {
+        let mark = if outliers.contains(&elf.index) {
+            "  outlier"
+        } else {
+            ""
+        };
+
+        println!(
+            "{:>6} {:>6} {:>10}{}",
+            elf.index + 1,
+            elf.items,
+            elf.total,
+            mark
+        );
     }
-}
 
-fn weights_by_elf(contents: &str) -> Elves {
-    let elves_weights = contents.split("\n\n");
+    println!();
+    println!("Elves:  {}", elves.len());
 
-    let weights = elves_weights
-        .map(|elf_weights| {
-            elf_weights
-                .split_whitespace()
-                .map(|weight| weight.parse::<u32>().expect("Unexpected character"))
-                .collect()
-        })
-        .collect();
+    if let (Some(mean), Some(median)) = (elves.mean(), elves.median()) {
+        println!("Mean:   {:.1}", mean);
+        println!("Median: {:.1}", median);
+    }
 
-    Elves::new(weights)
-}
+    for p in [90.0, 99.0] {
+        if let Some(total) = elves.percentile(p) {
+            println!("p{}:    {}", p, total);
+        }
+    }
 
-fn part_1(elves: &Elves) -> Option<u32> {
-    elves.weights.iter().map(|w| w.iter().sum()).max()
-}
+    let buckets = elves.histogram(HISTOGRAM_BUCKETS);
+    let highest = buckets.iter().map(|b| b.count).max().unwrap_or(0).max(1);
 
-fn part_2(elves: &Elves) -> Option<u32> {
-    let mut x = elves
-        .weights
-        .iter()
-        .map(|w| w.iter().sum())
-        .collect::<Vec<u32>>();
+    println!();
 
-    x.sort_by(|x, y| y.cmp(x));
+    for bucket in buckets {
+        let bar = "#".repeat(bucket.count * HISTOGRAM_WIDTH / highest);
+        let line = format!(
+            "{:>8}..{:<8} {:>5} {}",
+            bucket.start, bucket.end, bucket.count, bar
+        );
 
-    Some(x[..3].iter().sum::<u32>())
+        println!("{}", line.trim_end());
+    }
 }
 
-fn main() -> Result<(), Error> {
-    let filepath = "input.txt";
-    let contents = read_to_string(filepath).expect("The file doesn't exists");
+fn main() -> Result<(), String> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let report = take_flag(&mut args, REPORT_FLAG);
+
+    let contents = InputSource::from_arg(args.first().map(String::as_str), "input.txt")
+        .read()
+        .map_err(|err| err.to_string())?;
+    let elves = weights_by_elf(&contents).map_err(|err| err.to_string())?;
+
+    if report {
+        print_report(&elves);
+
+        return Ok(());
+    }
 
-    let elves = weights_by_elf(&contents);
     let part_1_result = part_1(&elves).expect("Something went wrong with part 1");
 
     println!("Part 1: {}", part_1_result);
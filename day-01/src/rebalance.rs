@@ -0,0 +1,203 @@
+use std::cmp::Reverse;
+
+use crate::Elves;
+
+/// Above this number of items, [`Elves::rebalance`] falls back to the heuristic.
+pub const EXACT_MAX_ITEMS: usize = 16;
+
+/// An item given by an elf to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub calories: u32,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// How to redistribute the items, and the totals of the elves once it's done.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub loads: Vec<u64>,
+    pub moves: Vec<Move>,
+}
+
+impl Plan {
+    pub fn max_load(&self) -> u64 {
+        self.loads.iter().copied().max().unwrap_or(0)
+    }
+}
+
+/// The items of every elf as `(owner, calories)`, the heaviest first.
+fn items_by_weight(elves: &Elves) -> Vec<(usize, u32)> {
+    let mut items: Vec<(usize, u32)> = elves
+        .weights
+        .iter()
+        .enumerate()
+        .flat_map(|(elf, items)| items.iter().map(move |&calories| (elf, calories)))
+        .collect();
+
+    items.sort_by_key(|&(_, calories)| Reverse(calories));
+
+    items
+}
+
+fn plan(elves_count: usize, items: &[(usize, u32)], assignment: &[usize]) -> Plan {
+    let mut loads = vec![0; elves_count];
+    let mut moves = vec![];
+
+    for (&(from, calories), &to) in items.iter().zip(assignment) {
+        loads[to] += u64::from(calories);
+
+        if from != to {
+            moves.push(Move { calories, from, to });
+        }
+    }
+
+    Plan { loads, moves }
+}
+
+/// Longest processing time first: each item, the heaviest first, goes to the least
+/// loaded elf, preferring its owner among equally loaded elves to save moves.
+fn greedy_assignment(elves_count: usize, items: &[(usize, u32)]) -> Vec<usize> {
+    let mut loads = vec![0u64; elves_count];
+
+    items
+        .iter()
+        .map(|&(owner, calories)| {
+            let lowest = loads.iter().copied().min().unwrap_or(0);
+            let elf = if loads[owner] == lowest {
+                owner
+            } else {
+                loads.iter().position(|&load| load == lowest).unwrap()
+            };
+
+            loads[elf] += u64::from(calories);
+
+            elf
+        })
+        .collect()
+}
+
+struct Search<'a> {
+    items: &'a [(usize, u32)],
+    loads: Vec<u64>,
+    assignment: Vec<usize>,
+    best: Vec<usize>,
+    best_max: u64,
+    lower_bound: u64,
+}
+
+impl Search<'_> {
+    fn explore(&mut self, item: usize, max: u64) {
+        if item == self.items.len() {
+            self.best_max = max;
+            self.best = self.assignment.clone();
+            return;
+        }
+
+        let calories = u64::from(self.items[item].1);
+
+        for elf in 0..self.loads.len() {
+            let load = self.loads[elf] + calories;
+
+            // Elves with the same load lead to the same solutions
+            if load >= self.best_max || self.loads[..elf].contains(&self.loads[elf]) {
+                continue;
+            }
+
+            self.loads[elf] = load;
+            self.assignment[item] = elf;
+            self.explore(item + 1, max.max(load));
+            self.loads[elf] -= calories;
+
+            if self.best_max == self.lower_bound {
+                return;
+            }
+        }
+    }
+}
+
+impl Elves {
+    /// Redistributes the items to minimize the highest total, exactly when there are at
+    /// most [`EXACT_MAX_ITEMS`] items and with [`Elves::rebalance_greedy`] otherwise.
+    pub fn rebalance(&self) -> Plan {
+        match self.weights.iter().map(Vec::len).sum::<usize>() {
+            n if n <= EXACT_MAX_ITEMS => self.rebalance_exact(),
+            _ => self.rebalance_greedy(),
+        }
+    }
+
+    /// A multiway partition of the items by the longest-processing-time-first
+    /// heuristic, whose highest total is at most 4/3 of the optimal one.
+    pub fn rebalance_greedy(&self) -> Plan {
+        let items = items_by_weight(self);
+        let assignment = greedy_assignment(self.len(), &items);
+
+        plan(self.len(), &items, &assignment)
+    }
+
+    /// The optimal partition by branch and bound, exponential in the number of items.
+    pub fn rebalance_exact(&self) -> Plan {
+        let items = items_by_weight(self);
+        let greedy = greedy_assignment(self.len(), &items);
+
+        if self.is_empty() {
+            return plan(0, &items, &greedy);
+        }
+
+        let total: u64 = self.totals().sum();
+        let elves_count = self.len() as u64;
+        let lower_bound = items
+            .first()
+            .map(|&(_, calories)| u64::from(calories))
+            .unwrap_or(0)
+            .max(total.div_ceil(elves_count));
+
+        let mut search = Search {
+            items: &items,
+            loads: vec![0; self.len()],
+            assignment: vec![0; items.len()],
+            best_max: plan(self.len(), &items, &greedy).max_load(),
+            best: greedy,
+            lower_bound,
+        };
+
+        if search.best_max > lower_bound {
+            // Only strictly better solutions are kept, so start one above the greedy one
+            search.best_max += 1;
+            search.explore(0, 0);
+        }
+
+        plan(self.len(), &items, &search.best)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{weights_by_elf, EXAMPLES};
+
+    use super::*;
+
+    #[test]
+    fn rebalance_example() {
+        let elves = weights_by_elf(EXAMPLES[0].input).unwrap();
+        let plan = elves.rebalance();
+
+        assert_eq!(plan.loads, vec![11000; 5]);
+        assert_eq!(plan.moves.len(), 4);
+    }
+
+    #[test]
+    fn exact_beats_greedy() {
+        let elves = Elves::new(vec![vec![3, 3, 2, 2, 2], vec![]]).unwrap();
+
+        assert_eq!(elves.rebalance_greedy().max_load(), 7);
+
+        let plan = elves.rebalance_exact();
+
+        assert_eq!(plan.max_load(), 6);
+        assert_eq!(
+            plan.moves.iter().map(|m| m.calories).sum::<u32>() as u64,
+            plan.loads[1]
+        );
+    }
+}
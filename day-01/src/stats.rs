@@ -0,0 +1,152 @@
+use crate::Elves;
+
+/// What a single elf carries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfStats {
+    pub index: usize,
+    pub items: usize,
+    pub total: u64,
+}
+
+/// Elves whose total falls in `start..end`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: u64,
+    pub end: u64,
+    pub count: usize,
+}
+
+impl Elves {
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    pub fn elf_stats(&self) -> Vec<ElfStats> {
+        self.weights
+            .iter()
+            .enumerate()
+            .map(|(index, items)| ElfStats {
+                index,
+                items: items.len(),
+                total: items.iter().map(|&weight| u64::from(weight)).sum(),
+            })
+            .collect()
+    }
+
+    fn sorted_totals(&self) -> Vec<u64> {
+        let mut totals: Vec<u64> = self.totals().collect();
+        totals.sort_unstable();
+
+        totals
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        match self.len() {
+            0 => None,
+            n => Some(self.totals().map(|total| total as f64).sum::<f64>() / n as f64),
+        }
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        let totals = self.sorted_totals();
+
+        match totals.len() {
+            0 => None,
+            n if n % 2 == 0 => Some((totals[n / 2 - 1] + totals[n / 2]) as f64 / 2.0),
+            n => Some(totals[n / 2] as f64),
+        }
+    }
+
+    /// The smallest total such that `p` percent of the elves carry at most as much
+    /// (nearest-rank method), with `p` clamped to `0..=100`.
+    pub fn percentile(&self, p: f64) -> Option<u64> {
+        let totals = self.sorted_totals();
+        let rank = (p.clamp(0.0, 100.0) / 100.0 * totals.len() as f64).ceil() as usize;
+
+        totals.get(rank.max(1) - 1).copied()
+    }
+
+    /// Splits the range of totals into `count` buckets of the same width.
+    pub fn histogram(&self, count: usize) -> Vec<Bucket> {
+        let totals = self.sorted_totals();
+
+        let (min, max) = match (totals.first(), totals.last()) {
+            (Some(&min), Some(&max)) if count > 0 => (min, max),
+            _ => return vec![],
+        };
+        let width = ((max - min) / count as u64 + 1).max(1);
+
+        let mut buckets: Vec<Bucket> = (0..count as u64)
+            .map(|idx| Bucket {
+                start: min + idx * width,
+                end: min + (idx + 1) * width,
+                count: 0,
+            })
+            .collect();
+
+        for total in totals {
+            buckets[((total - min) / width) as usize].count += 1;
+        }
+
+        buckets
+    }
+
+    /// The elves whose total is further than 1.5 interquartile ranges from the
+    /// first or third quartile.
+    pub fn outliers(&self) -> Vec<ElfStats> {
+        let (q1, q3) = match (self.percentile(25.0), self.percentile(75.0)) {
+            (Some(q1), Some(q3)) => (q1 as f64, q3 as f64),
+            _ => return vec![],
+        };
+        let fence = 1.5 * (q3 - q1);
+
+        self.elf_stats()
+            .into_iter()
+            .filter(|elf| {
+                let total = elf.total as f64;
+                total < q1 - fence || total > q3 + fence
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{weights_by_elf, EXAMPLES};
+
+    use super::*;
+
+    #[test]
+    fn summary() {
+        let elves = weights_by_elf(EXAMPLES[0].input).unwrap();
+
+        assert_eq!(
+            elves.elf_stats()[0],
+            ElfStats {
+                index: 0,
+                items: 3,
+                total: 6000
+            }
+        );
+        assert_eq!(elves.mean(), Some(11000.0));
+        assert_eq!(elves.median(), Some(10000.0));
+        assert_eq!(elves.percentile(50.0), Some(10000));
+        assert_eq!(elves.percentile(100.0), Some(24000));
+        assert_eq!(elves.percentile(0.0), Some(4000));
+    }
+
+    #[test]
+    fn histogram_and_outliers() {
+        let elves = weights_by_elf(EXAMPLES[0].input).unwrap();
+        let counts: Vec<_> = elves.histogram(2).iter().map(|b| b.count).collect();
+
+        assert_eq!(counts, vec![4, 1]);
+        assert_eq!(elves.outliers().len(), 1);
+        assert_eq!(elves.outliers()[0].index, 3);
+        assert!(Elves::new(vec![]).unwrap().histogram(3).is_empty());
+    }
+}
@@ -0,0 +1,121 @@
+use std::io::{BufRead, Lines};
+
+use common::{ParseError, StreamingSolution};
+
+use crate::{overflow, top_sum, Elves, TopN};
+
+/// Total calories carried by each elf, read one line at a time.
+pub struct ElfTotals<R> {
+    lines: Lines<R>,
+    line_number: usize,
+    elf: usize,
+}
+
+impl<R: BufRead> ElfTotals<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            line_number: 0,
+            elf: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for ElfTotals<R> {
+    type Item = Result<u64, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut total: Option<u64> = None;
+
+        for line in self.lines.by_ref() {
+            self.line_number += 1;
+
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err.into())),
+            };
+            let weight = line.trim();
+
+            if weight.is_empty() {
+                if total.is_some() {
+                    break;
+                }
+                continue;
+            }
+
+            if total.is_none() {
+                self.elf += 1;
+            }
+
+            let sum = weight
+                .parse::<u32>()
+                .map_err(|_| {
+                    let expected = format!("a number of calories for elf {}", self.elf);
+                    ParseError::unexpected_token(&line, weight, &expected)
+                })
+                .and_then(|weight| {
+                    total
+                        .unwrap_or(0)
+                        .checked_add(u64::from(weight))
+                        .ok_or_else(|| overflow::<u64>(self.elf - 1))
+                });
+
+            match sum {
+                Ok(sum) => total = Some(sum),
+                Err(err) => return Some(Err(err.at_line(self.line_number, &line))),
+            }
+        }
+
+        total.map(Ok)
+    }
+}
+
+impl StreamingSolution for Elves {
+    fn part_1_from_reader<R: BufRead>(reader: R) -> Result<Option<u64>, ParseError> {
+        let mut max = None;
+
+        for total in ElfTotals::new(reader) {
+            max = max.max(Some(total?));
+        }
+
+        Ok(max)
+    }
+
+    fn part_2_from_reader<R: BufRead>(reader: R) -> Result<Option<u64>, ParseError> {
+        let mut top = TopN::new(3);
+
+        for (index, total) in ElfTotals::new(reader).enumerate() {
+            top.push(index, total?);
+        }
+
+        let top = top.into_sorted_vec();
+
+        match top.len() {
+            3 => top_sum(&top).map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EXAMPLES;
+
+    #[test]
+    fn totals_from_reader() {
+        let totals: Vec<_> = ElfTotals::new(EXAMPLES[0].input.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(totals, vec![6000, 4000, 11000, 24000, 10000]);
+    }
+
+    #[test]
+    fn parts_from_reader() {
+        let input = EXAMPLES[0].input.as_bytes();
+
+        assert_eq!(Elves::part_1_from_reader(input), Ok(Some(24000)));
+        assert_eq!(Elves::part_2_from_reader(input), Ok(Some(45000)));
+    }
+}
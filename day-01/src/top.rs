@@ -0,0 +1,72 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// The `n` elves carrying the most calories among those pushed so far, kept in a
+/// min-heap of at most `n` entries so that selecting them takes O(elves * log n).
+pub struct TopN {
+    n: usize,
+    // Earlier elves win ties, so a higher index ranks lower
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl TopN {
+    pub fn new(n: usize) -> Self {
+        // The heap grows with the elves pushed, as `n` can be far more than there are
+        Self {
+            n,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    /// Offers the `total` of the elf at `index`, evicting the lowest one when full.
+    pub fn push(&mut self, index: usize, total: u64) {
+        let entry = Reverse((total, Reverse(index)));
+
+        if self.heap.len() < self.n {
+            self.heap.push(entry);
+        } else if let Some(mut lowest) = self.heap.peek_mut() {
+            if entry < *lowest {
+                *lowest = entry;
+            }
+        }
+    }
+
+    /// The `(index, total)` of the selected elves, from the highest total to the lowest.
+    pub fn into_sorted_vec(self) -> Vec<(usize, u64)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(index)))| (index, total))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_highest() {
+        let mut top = TopN::new(2);
+
+        for (index, total) in [5, 1, 9, 5, 3].into_iter().enumerate() {
+            top.push(index, total);
+        }
+
+        assert_eq!(top.into_sorted_vec(), vec![(2, 9), (0, 5)]);
+    }
+
+    #[test]
+    fn fewer_than_n() {
+        let mut top = TopN::new(3);
+        top.push(0, 4);
+
+        assert_eq!(top.into_sorted_vec(), vec![(0, 4)]);
+        assert_eq!(TopN::new(0).into_sorted_vec(), vec![]);
+
+        let mut top = TopN::new(usize::MAX);
+        top.push(0, 4);
+        top.push(1, 7);
+
+        assert_eq!(top.into_sorted_vec(), vec![(1, 7), (0, 4)]);
+    }
+}
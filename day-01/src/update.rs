@@ -0,0 +1,196 @@
+use std::cmp::Reverse;
+
+use common::ParseError;
+
+use crate::{checked_total, overflow, Elves};
+
+/// Changes to the elves keeping their totals and ranking up to date, so that
+/// [`Elves::top_n`] doesn't need to add up every item again.
+impl Elves {
+    fn set_total(&mut self, elf: usize, total: u64) {
+        self.ranking.remove(&(Reverse(self.totals[elf]), elf));
+        self.ranking.insert((Reverse(total), elf));
+        self.totals[elf] = total;
+    }
+
+    /// Moves the elves after `elf` up or down the input by `delta` lines.
+    fn shift_lines(&mut self, elf: usize, delta: isize) {
+        for line in &mut self.lines[(elf + 1)..] {
+            *line = line.saturating_add_signed(delta);
+        }
+    }
+
+    /// Gives an item to `elf`, in O(log elves). Fails without giving it if the calories
+    /// carried by the elf would overflow a `u64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such elf.
+    pub fn add_item(&mut self, elf: usize, calories: u32) -> Result<(), ParseError> {
+        let total = self.totals[elf]
+            .checked_add(u64::from(calories))
+            .ok_or_else(|| overflow::<u64>(elf))?;
+
+        self.weights[elf].push(calories);
+        self.set_total(elf, total);
+        self.shift_lines(elf, 1);
+
+        Ok(())
+    }
+
+    /// Takes the item at `item` away from `elf` and returns its calories, in O(log elves).
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such elf or item.
+    pub fn remove_item(&mut self, elf: usize, item: usize) -> u32 {
+        let calories = self.weights[elf].remove(item);
+        let total = self.totals[elf]
+            .checked_sub(u64::from(calories))
+            .expect("The total includes the calories of every item");
+        self.set_total(elf, total);
+        self.shift_lines(elf, -1);
+
+        calories
+    }
+
+    /// Adds an elf after the others and returns its index, in O(log elves). Fails if the
+    /// calories it carries overflow a `u64`.
+    pub fn add_elf(&mut self, items: Vec<u32>) -> Result<usize, ParseError> {
+        let elf = self.len();
+        let line = match (self.lines.last(), self.weights.last()) {
+            (Some(line), Some(last_items)) => line + last_items.len() + 1,
+            _ => 1,
+        };
+        let total = checked_total(elf, &items, line)?;
+
+        self.weights.push(items);
+        self.lines.push(line);
+        self.totals.push(total);
+        self.ranking.insert((Reverse(total), elf));
+
+        Ok(elf)
+    }
+
+    /// Removes `elf` and returns its items. The following elves are shifted down by one,
+    /// so this takes O(elves * log elves) unless it's the last one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such elf.
+    pub fn remove_elf(&mut self, elf: usize) -> Vec<u32> {
+        for (idx, &total) in self.totals.iter().enumerate().skip(elf) {
+            self.ranking.remove(&(Reverse(total), idx));
+        }
+
+        // The elf and the blank line after it
+        self.shift_lines(elf, -(self.weights[elf].len() as isize + 1));
+
+        let items = self.weights.remove(elf);
+        self.lines.remove(elf);
+        self.totals.remove(elf);
+
+        for (idx, &total) in self.totals.iter().enumerate().skip(elf) {
+            self.ranking.insert((Reverse(total), idx));
+        }
+
+        items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{part_1, part_2, weights_by_elf, EXAMPLES};
+
+    use super::*;
+
+    #[test]
+    fn updates_match_a_fresh_parse() {
+        let mut elves = weights_by_elf(EXAMPLES[0].input).unwrap();
+
+        elves.add_item(1, 30000).unwrap();
+        assert_eq!(part_1(&elves), Some(34000));
+        assert_eq!(elves.top_n(2), vec![(1, 34000), (3, 24000)]);
+
+        assert_eq!(elves.remove_item(3, 2), 9000);
+        assert_eq!(elves.top_n(2), vec![(1, 34000), (3, 15000)]);
+
+        assert_eq!(elves.add_elf(vec![50000]), Ok(5));
+        assert_eq!(elves.remove_elf(1), vec![4000, 30000]);
+        assert_eq!(elves.top_n(2), vec![(4, 50000), (2, 15000)]);
+
+        let fresh = Elves::new(elves.weights.clone()).unwrap();
+
+        assert_eq!(elves.top_n(10), fresh.top_n(10));
+        assert_eq!(part_2(&elves), part_2(&fresh));
+    }
+
+    #[test]
+    fn remove_first_and_last_elves() {
+        let mut elves = weights_by_elf(EXAMPLES[0].input).unwrap();
+
+        assert_eq!(elves.remove_elf(4), vec![10000]);
+        assert_eq!(elves.remove_elf(0), vec![1000, 2000, 3000]);
+        assert_eq!(
+            elves.top_n(usize::MAX),
+            vec![(2, 24000), (1, 11000), (0, 4000)]
+        );
+
+        let fresh = Elves::new(elves.weights.clone()).unwrap();
+
+        assert_eq!(elves.lines, fresh.lines);
+        assert_eq!(elves.top_n(usize::MAX), fresh.top_n(usize::MAX));
+
+        assert_eq!(elves.remove_elf(2), vec![7000, 8000, 9000]);
+        assert_eq!(elves.remove_elf(1), vec![5000, 6000]);
+        assert_eq!(elves.remove_elf(0), vec![4000]);
+        assert!(elves.is_empty());
+        assert_eq!(part_1(&elves), None);
+        assert_eq!(elves.add_elf(vec![1]), Ok(0));
+        assert_eq!(elves.lines, vec![1]);
+    }
+
+    #[test]
+    fn ties_after_updates() {
+        let mut elves = weights_by_elf("1\n\n2\n\n3\n").unwrap();
+
+        // The first elf wins ties, whatever the order of the updates
+        elves.add_item(2, 1).unwrap();
+        elves.add_item(0, 3).unwrap();
+        assert_eq!(elves.top_n(2), vec![(0, 4), (2, 4)]);
+
+        assert_eq!(elves.remove_item(0, 0), 1);
+        elves.add_item(1, 1).unwrap();
+        assert_eq!(elves.add_elf(vec![1, 2]), Ok(3));
+        assert_eq!(
+            elves.top_n(usize::MAX),
+            vec![(2, 4), (0, 3), (1, 3), (3, 3)]
+        );
+        assert_eq!(
+            elves.top_n(usize::MAX),
+            Elves::new(elves.weights.clone()).unwrap().top_n(usize::MAX)
+        );
+    }
+
+    #[test]
+    fn overflows_are_located_after_updates() {
+        let mut elves = weights_by_elf("1\n\n4000000000\n300000000\n").unwrap();
+        let overflow_line = |elves: &Elves| elves.max_total::<u32>().unwrap_err().line();
+
+        assert_eq!(overflow_line(&elves), Some(4));
+
+        elves.add_item(0, 2).unwrap();
+        assert_eq!(overflow_line(&elves), Some(5));
+
+        elves.remove_item(0, 0);
+        elves.remove_item(0, 0);
+        assert_eq!(overflow_line(&elves), Some(3));
+
+        elves.remove_elf(0);
+        assert_eq!(overflow_line(&elves), Some(2));
+        assert_eq!(
+            elves.lines,
+            Elves::new(elves.weights.clone()).unwrap().lines
+        );
+    }
+}
@@ -0,0 +1 @@
+common::criterion_benches!(day_02::Game);
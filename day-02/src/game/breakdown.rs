@@ -0,0 +1,150 @@
+use std::{io::Write, str::FromStr};
+
+use serde::Serialize;
+
+use super::{outcome::Outcome, rules::ScoringRules, turn::Turn, variant::Variant};
+
+/// The detail of a scored turn.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Round {
+    pub number: usize,
+    pub opponent: String,
+    pub mine: String,
+    pub outcome: Outcome,
+    pub shape_points: usize,
+    pub outcome_points: usize,
+    /// The score of the game up to this round, included.
+    pub total: usize,
+    /// How many more points the best shape would have scored.
+    pub missed: usize,
+}
+
+impl Round {
+    pub fn score(&self) -> usize {
+        self.shape_points + self.outcome_points
+    }
+}
+
+/// The rounds of `turns`, numbered from 1.
+pub(crate) fn rounds(turns: &[Turn], variant: &Variant, rules: &ScoringRules) -> Vec<Round> {
+    let mut total = 0;
+
+    turns
+        .iter()
+        .enumerate()
+        .map(|(idx, turn)| {
+            let shape_points = rules.shape_points(turn.my_choice());
+            let outcome_points = rules.outcome_points(turn.outcome());
+            total += shape_points + outcome_points;
+
+            let best = variant
+                .choices()
+                .map(|mine| Turn::new(variant, turn.opponent_choice(), mine).score(rules))
+                .max()
+                .unwrap_or(0);
+
+            Round {
+                number: idx + 1,
+                opponent: variant.name(turn.opponent_choice()).to_string(),
+                mine: variant.name(turn.my_choice()).to_string(),
+                outcome: turn.outcome(),
+                shape_points,
+                outcome_points,
+                total,
+                missed: best - (shape_points + outcome_points),
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("Expected `csv` or `json`, found `{}`", s)),
+        }
+    }
+}
+
+const CSV_HEADER: &str = "number,opponent,mine,outcome,shape_points,outcome_points,total,missed";
+
+/// Writes the match log of `rounds` to `writer`, as CSV with a header or a JSON array.
+pub fn export<W: Write>(rounds: &[Round], format: Format, mut writer: W) -> std::io::Result<()> {
+    match format {
+        Format::Csv => {
+            writeln!(writer, "{}", CSV_HEADER)?;
+
+            for round in rounds {
+                writeln!(
+                    writer,
+                    "{},{},{},{},{},{},{},{}",
+                    round.number,
+                    round.opponent,
+                    round.mine,
+                    outcome_name(round.outcome),
+                    round.shape_points,
+                    round.outcome_points,
+                    round.total,
+                    round.missed
+                )?;
+            }
+
+            Ok(())
+        }
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut writer, rounds)?;
+            writeln!(writer)
+        }
+    }
+}
+
+fn outcome_name(outcome: Outcome) -> &'static str {
+    match outcome {
+        Outcome::Lose => "lose",
+        Outcome::Draw => "draw",
+        Outcome::Win => "win",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Game, Part, EXAMPLES};
+
+    use super::*;
+
+    #[test]
+    fn breakdown_and_export() {
+        let game = Game::from_file_content(EXAMPLES[0].input, Part::One).unwrap();
+        let rounds = game.rounds(&Variant::classic(), &ScoringRules::default());
+
+        assert_eq!(
+            rounds.iter().map(Round::score).collect::<Vec<_>>(),
+            [8, 1, 6]
+        );
+        assert_eq!(rounds.last().unwrap().total, 15);
+        assert_eq!(rounds[1].missed, 8);
+
+        let mut csv = vec![];
+        export(&rounds, Format::Csv, &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+
+        assert_eq!(csv.lines().count(), 4);
+        assert_eq!(csv.lines().nth(2), Some("2,paper,rock,lose,1,0,9,8"));
+
+        let mut json = vec![];
+        export(&rounds, Format::Json, &mut json).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+
+        assert_eq!(json[0]["outcome"], "win");
+        assert_eq!(json[2]["total"], 15);
+    }
+}
@@ -1,41 +1,17 @@
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub enum Choice {
-    Rock,
-    Paper,
-    Scissors,
-}
+/// A shape, as its position in the cycle of shapes of a [`Variant`](super::Variant).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Choice(usize);
 
 impl Choice {
-    pub fn score(&self) -> usize {
-        match self {
-            Self::Rock => 1,
-            Self::Paper => 2,
-            Self::Scissors => 3,
-        }
-    }
-
-    pub fn from_letter(letter: &char) -> Option<Self> {
-        match letter {
-            'A' | 'X' => Some(Self::Rock),
-            'B' | 'Y' => Some(Self::Paper),
-            'C' | 'Z' => Some(Self::Scissors),
-            _ => None,
-        }
-    }
+    pub const ROCK: Self = Self(0);
+    pub const PAPER: Self = Self(1);
+    pub const SCISSORS: Self = Self(2);
 
-    pub fn wins_vs(&self) -> Self {
-        match &self {
-            Self::Rock => Self::Scissors,
-            Self::Paper => Self::Rock,
-            Self::Scissors => Self::Paper,
-        }
+    pub const fn new(index: usize) -> Self {
+        Self(index)
     }
 
-    pub fn loses_vs(&self) -> Self {
-        match &self {
-            Self::Rock => Self::Paper,
-            Self::Paper => Self::Scissors,
-            Self::Scissors => Self::Rock,
-        }
+    pub const fn index(&self) -> usize {
+        self.0
     }
 }
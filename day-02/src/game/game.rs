@@ -1,28 +1,113 @@
-use super::turn::Turn;
+use std::io::BufRead;
 
+use common::ParseError;
+
+use super::{
+    breakdown::{self, Round},
+    choice::Choice,
+    rules::ScoringRules,
+    strategy::Strategy,
+    tokenizer::ParseMode,
+    turn::Turn,
+    variant::Variant,
+};
+
+#[derive(Clone, Copy)]
 pub enum Part {
     One,
     Two,
 }
 
+#[derive(Debug)]
 pub struct Game {
     turns: Vec<Turn>,
 }
 
 impl Game {
-    pub fn score(&self) -> usize {
-        self.turns.iter().map(|turn| turn.score()).sum()
+    pub fn score(&self, rules: &ScoringRules) -> usize {
+        self.turns.iter().map(|turn| turn.score(rules)).sum()
+    }
+
+    /// The shapes I play, in order.
+    pub fn my_choices(&self) -> Vec<Choice> {
+        self.turns.iter().map(Turn::my_choice).collect()
+    }
+
+    /// The detail of every turn, to audit how the score adds up.
+    pub fn rounds(&self, variant: &Variant, rules: &ScoringRules) -> Vec<Round> {
+        breakdown::rounds(&self.turns, variant, rules)
+    }
+
+    /// Replays the opponent's choices of the guide against `strategy` instead of mine.
+    pub fn simulate(
+        &self,
+        strategy: &mut dyn Strategy,
+        variant: &Variant,
+        rules: &ScoringRules,
+    ) -> usize {
+        let history: Vec<Choice> = self.turns.iter().map(Turn::opponent_choice).collect();
+
+        history
+            .iter()
+            .enumerate()
+            .map(|(idx, &opponent)| {
+                let mine = strategy.choose(variant, &history[..idx]);
+
+                Turn::new(variant, opponent, mine).score(rules)
+            })
+            .sum()
+    }
+
+    pub fn from_file_content(file_content: &str, part: Part) -> Result<Self, ParseError> {
+        Self::from_file_content_with(file_content, part, &Variant::classic(), ParseMode::Strict)
     }
 
-    pub fn from_file_content(file_content: &str, part: Part) -> Result<Self, String> {
+    /// Reads the strategy guide of a game played with the shapes and letters of `variant`.
+    pub fn from_file_content_with(
+        file_content: &str,
+        part: Part,
+        variant: &Variant,
+        mode: ParseMode,
+    ) -> Result<Self, ParseError> {
         let turns: Vec<Turn> = file_content
             .lines()
-            .map(|line| match part {
-                Part::One => Turn::from_line_with_choice(line),
-                Part::Two => Turn::from_line_with_outcome(line),
+            .enumerate()
+            .filter_map(|(idx, line)| {
+                Self::turn_from_line(idx + 1, line, part, variant, mode).transpose()
             })
             .collect::<Result<_, _>>()?;
 
         Ok(Game { turns })
     }
+
+    /// Scores the strategy guide one line at a time, without keeping its turns around.
+    pub fn score_from_reader<R: BufRead>(
+        reader: R,
+        part: Part,
+        variant: &Variant,
+        rules: &ScoringRules,
+    ) -> Result<usize, ParseError> {
+        reader
+            .lines()
+            .enumerate()
+            .try_fold(0, |score, (idx, line)| {
+                let turn = Self::turn_from_line(idx + 1, &line?, part, variant, ParseMode::Strict)?;
+
+                Ok(score + turn.map_or(0, |turn| turn.score(rules)))
+            })
+    }
+
+    fn turn_from_line(
+        number: usize,
+        line: &str,
+        part: Part,
+        variant: &Variant,
+        mode: ParseMode,
+    ) -> Result<Option<Turn>, ParseError> {
+        match part {
+            Part::One => Turn::from_line_with_choice(line, variant, mode),
+            Part::Two => Turn::from_line_with_outcome(line, variant, mode),
+        }
+        .map_err(|err| err.at_line(number, line))
+    }
 }
@@ -1,7 +1,24 @@
+mod breakdown;
 mod choice;
+#[allow(clippy::module_inception)]
 mod game;
+mod optimizer;
 mod outcome;
+mod rules;
+mod strategy;
+mod tokenizer;
+mod tournament;
 mod turn;
+mod variant;
 
+pub use breakdown::{export, Format, Round};
+pub use choice::Choice;
 pub use game::Game;
 pub use game::Part;
+pub use optimizer::{Mapping, MappingScore, Optimization, StrategyGuide};
+pub use outcome::Outcome;
+pub use rules::ScoringRules;
+pub use strategy::{Always, FrequencyCounter, MarkovPredictor, Random, Strategy};
+pub use tokenizer::ParseMode;
+pub use tournament::{MatchResult, Player, Standing, Tournament};
+pub use variant::Variant;
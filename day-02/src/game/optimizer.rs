@@ -0,0 +1,228 @@
+use std::{cmp::Reverse, collections::BTreeMap};
+
+use common::ParseError;
+
+use super::{
+    choice::Choice,
+    outcome::Outcome,
+    rules::ScoringRules,
+    tokenizer::{tokenize, ParseMode},
+    turn::Turn,
+    variant::Variant,
+};
+
+const OUTCOMES: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+/// What the letters of the second column of the guide stand for, in the order of
+/// [`StrategyGuide::letters`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mapping {
+    Choices(Vec<Choice>),
+    Outcomes(Vec<Outcome>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappingScore {
+    pub mapping: Mapping,
+    pub score: usize,
+}
+
+/// The scores reachable by following a strategy guide.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Optimization {
+    /// Playing the best shape of every round.
+    pub best: usize,
+    /// Playing the worst shape of every round.
+    pub worst: usize,
+    /// Every mapping of the letters, from the highest score to the lowest.
+    pub mappings: Vec<MappingScore>,
+}
+
+impl Optimization {
+    pub fn best_mapping(&self) -> Option<&MappingScore> {
+        self.mappings.first()
+    }
+}
+
+/// The opponent's choices of a strategy guide, with the second column left uninterpreted.
+#[derive(Debug)]
+pub struct StrategyGuide {
+    letters: Vec<char>,
+    // How many rounds have each opponent choice and letter
+    rounds: BTreeMap<(Choice, char), usize>,
+}
+
+impl StrategyGuide {
+    pub fn from_file_content(
+        file_content: &str,
+        variant: &Variant,
+        mode: ParseMode,
+    ) -> Result<Self, ParseError> {
+        let mut rounds = BTreeMap::new();
+
+        for (idx, line) in file_content.lines().enumerate() {
+            let round = Self::round_from_line(line, variant, mode)
+                .map_err(|err| err.at_line(idx + 1, line))?;
+
+            if let Some(round) = round {
+                *rounds.entry(round).or_insert(0) += 1;
+            }
+        }
+
+        let mut letters: Vec<char> = rounds.keys().map(|&(_, letter)| letter).collect();
+        letters.sort_unstable();
+        letters.dedup();
+
+        Ok(Self { letters, rounds })
+    }
+
+    fn round_from_line(
+        line: &str,
+        variant: &Variant,
+        mode: ParseMode,
+    ) -> Result<Option<(Choice, char)>, ParseError> {
+        let opponent_expected = Turn::opponent_expected(variant);
+        let Some((opponent, letter)) = tokenize(
+            line,
+            mode,
+            &opponent_expected,
+            "a letter in the second column",
+        )?
+        else {
+            return Ok(None);
+        };
+
+        let choice = variant
+            .opponent_choice(opponent.letter)
+            .ok_or_else(|| ParseError::unexpected_token(line, opponent.text, &opponent_expected))?;
+
+        Ok(Some((choice, letter.letter)))
+    }
+
+    /// The distinct letters of the second column, in alphabetical order.
+    pub fn letters(&self) -> &[char] {
+        &self.letters
+    }
+
+    /// Scores every way of reading the second column as shapes to play or as outcomes
+    /// to reach, which are `n! / (n - letters)!` and `3! / (3 - letters)!` mappings.
+    pub fn optimize(&self, variant: &Variant, rules: &ScoringRules) -> Optimization {
+        let score_of = |mine: Choice, opponent: Choice| {
+            rules.shape_points(mine) + rules.outcome_points(variant.outcome(mine, opponent))
+        };
+
+        let scores_against =
+            |opponent: Choice| variant.choices().map(move |mine| score_of(mine, opponent));
+        let best = self.score_with(|opponent, _| scores_against(opponent).max().unwrap_or(0));
+        let worst = self.score_with(|opponent, _| scores_against(opponent).min().unwrap_or(0));
+
+        let shapes: Vec<Choice> = variant.choices().collect();
+        let choices = arrangements(&shapes, self.letters.len())
+            .into_iter()
+            .map(|choices| {
+                let score = self.score_with(|opponent, letter| score_of(choices[letter], opponent));
+
+                MappingScore {
+                    mapping: Mapping::Choices(choices),
+                    score,
+                }
+            });
+        let outcomes = arrangements(&OUTCOMES, self.letters.len())
+            .into_iter()
+            .map(|outcomes| {
+                let score = self.score_with(|opponent, letter| {
+                    score_of(variant.choice_for(opponent, outcomes[letter]), opponent)
+                });
+
+                MappingScore {
+                    mapping: Mapping::Outcomes(outcomes),
+                    score,
+                }
+            });
+
+        let mut mappings: Vec<MappingScore> = choices.chain(outcomes).collect();
+        mappings.sort_by_key(|mapping| Reverse(mapping.score));
+
+        Optimization {
+            best,
+            worst,
+            mappings,
+        }
+    }
+
+    /// The total score when a round against `opponent` with the `letter`-th letter
+    /// scores `round_score(opponent, letter)`.
+    fn score_with(&self, round_score: impl Fn(Choice, usize) -> usize) -> usize {
+        self.rounds
+            .iter()
+            .map(|(&(opponent, letter), &count)| {
+                let letter = self.letters.binary_search(&letter).unwrap();
+                round_score(opponent, letter) * count
+            })
+            .sum()
+    }
+}
+
+/// Every ordered selection of `k` distinct items.
+fn arrangements<T: Clone>(items: &[T], k: usize) -> Vec<Vec<T>> {
+    if k == 0 {
+        return vec![vec![]];
+    }
+
+    (0..items.len())
+        .flat_map(|idx| {
+            let mut rest = items.to_vec();
+            let item = rest.remove(idx);
+
+            arrangements(&rest, k - 1).into_iter().map(move |mut tail| {
+                tail.insert(0, item.clone());
+                tail
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn optimize_example() {
+        let variant = Variant::classic();
+        let guide =
+            StrategyGuide::from_file_content("A Y\nB X\nC Z\n", &variant, ParseMode::Strict)
+                .unwrap();
+        let optimization = guide.optimize(&variant, &ScoringRules::default());
+
+        assert_eq!(guide.letters(), ['X', 'Y', 'Z']);
+        assert_eq!(optimization.mappings.len(), 12);
+        assert_eq!(optimization.best, 8 + 9 + 7);
+        assert_eq!(optimization.worst, 3 + 1 + 2);
+
+        let score_of = |mapping: Mapping| {
+            optimization
+                .mappings
+                .iter()
+                .find(|m| m.mapping == mapping)
+                .map(|m| m.score)
+        };
+
+        // The two readings of the puzzle
+        let puzzle_choices = vec![Choice::ROCK, Choice::PAPER, Choice::SCISSORS];
+        assert_eq!(score_of(Mapping::Choices(puzzle_choices)), Some(15));
+        assert_eq!(score_of(Mapping::Outcomes(OUTCOMES.to_vec())), Some(12));
+
+        let best = optimization.best_mapping().unwrap();
+        assert!(best.score <= optimization.best);
+        assert!(optimization
+            .mappings
+            .iter()
+            .all(|m| m.score >= optimization.worst));
+    }
+
+    #[test]
+    fn arrangements_count() {
+        assert_eq!(arrangements(&[1, 2, 3, 4, 5], 3).len(), 60);
+        assert_eq!(arrangements(&[1, 2, 3], 4).len(), 0);
+    }
+}
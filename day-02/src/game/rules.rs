@@ -0,0 +1,156 @@
+use std::{collections::BTreeMap, fs::read_to_string, str::FromStr};
+
+use toml::{Table, Value};
+
+use super::{choice::Choice, outcome::Outcome, variant::Variant};
+
+/// Points given for the outcome of a turn and for the shape I chose, read from a TOML
+/// file where any missing key keeps the points of the puzzle. A shape is worth its
+/// position in the cycle of its [`Variant`] plus one unless told otherwise:
+///
+/// ```toml
+/// [outcome]
+/// win = 6
+/// draw = 3
+/// lose = 0
+///
+/// [shape]
+/// rock = 1
+/// paper = 2
+/// scissors = 3
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoringRules {
+    win: usize,
+    draw: usize,
+    lose: usize,
+    shapes: BTreeMap<Choice, usize>,
+}
+
+impl Default for ScoringRules {
+    /// The rules of the puzzle.
+    fn default() -> Self {
+        Self {
+            win: 6,
+            draw: 3,
+            lose: 0,
+            shapes: BTreeMap::new(),
+        }
+    }
+}
+
+fn points(table: &str, key: &str, value: &Value) -> Result<usize, String> {
+    value
+        .as_integer()
+        .and_then(|points| usize::try_from(points).ok())
+        .ok_or(format!("Expected a number of points for {}.{}", table, key))
+}
+
+impl ScoringRules {
+    /// Reads the rules from a file naming the shapes of `variant`.
+    pub fn from_file(filename: &str, variant: &Variant) -> Result<Self, String> {
+        let file_content = read_to_string(filename).map_err(|e| format!("{}: {}", filename, e))?;
+
+        Self::from_toml(&file_content, variant).map_err(|e| format!("{}: {}", filename, e))
+    }
+
+    pub fn from_toml(s: &str, variant: &Variant) -> Result<Self, String> {
+        let table: Table = s.parse().map_err(|e: toml::de::Error| e.to_string())?;
+        let mut rules = Self::default();
+
+        for (table_key, entries) in table {
+            let entries = entries
+                .as_table()
+                .ok_or(format!("Expected a table for `{}`", table_key))?;
+
+            for (key, value) in entries {
+                let points = points(&table_key, key, value)?;
+                let unknown = || format!("Unknown rule: {}.{}", table_key, key);
+
+                match (table_key.as_str(), key.as_str()) {
+                    ("outcome", "win") => *rules.outcome_slot(Outcome::Win) = points,
+                    ("outcome", "draw") => *rules.outcome_slot(Outcome::Draw) = points,
+                    ("outcome", "lose") => *rules.outcome_slot(Outcome::Lose) = points,
+                    ("shape", name) => {
+                        let Some(shape) = variant.find(name) else {
+                            return Err(unknown());
+                        };
+
+                        rules.shapes.insert(shape, points);
+                    }
+                    _ => return Err(unknown()),
+                };
+            }
+        }
+
+        Ok(rules)
+    }
+
+    pub fn with_outcome(mut self, outcome: Outcome, points: usize) -> Self {
+        *self.outcome_slot(outcome) = points;
+        self
+    }
+
+    pub fn with_shape(mut self, shape: Choice, points: usize) -> Self {
+        self.shapes.insert(shape, points);
+        self
+    }
+
+    pub fn outcome_points(&self, outcome: Outcome) -> usize {
+        match outcome {
+            Outcome::Win => self.win,
+            Outcome::Draw => self.draw,
+            Outcome::Lose => self.lose,
+        }
+    }
+
+    pub fn shape_points(&self, shape: Choice) -> usize {
+        self.shapes
+            .get(&shape)
+            .copied()
+            .unwrap_or(shape.index() + 1)
+    }
+
+    fn outcome_slot(&mut self, outcome: Outcome) -> &mut usize {
+        match outcome {
+            Outcome::Win => &mut self.win,
+            Outcome::Draw => &mut self.draw,
+            Outcome::Lose => &mut self.lose,
+        }
+    }
+}
+
+impl FromStr for ScoringRules {
+    type Err = String;
+
+    /// Rules of the classic rock-paper-scissors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_toml(s, &Variant::classic())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rules() {
+        let rules: ScoringRules = "[outcome]\nwin = 10\n\n[shape]\nrock = 0\n"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            rules,
+            ScoringRules::default()
+                .with_outcome(Outcome::Win, 10)
+                .with_shape(Choice::ROCK, 0)
+        );
+        assert!("[shape]\nlizard = 4\n".parse::<ScoringRules>().is_err());
+        assert!("[outcome]\nwin = -1\n".parse::<ScoringRules>().is_err());
+
+        let rules = ScoringRules::from_toml("[shape]\nspock = 9\n", &Variant::rpsls()).unwrap();
+
+        assert_eq!(rules.shape_points(Choice::new(1)), 9);
+        assert_eq!(rules.shape_points(Choice::new(4)), 5);
+    }
+}
@@ -0,0 +1,207 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use rand::Rng;
+
+use super::{choice::Choice, outcome::Outcome, variant::Variant};
+
+/// A way of choosing my shape knowing only what the opponent played so far.
+pub trait Strategy {
+    fn name(&self, variant: &Variant) -> String;
+
+    /// The shape to play against the opponent, whose previous choices are `history`.
+    fn choose(&mut self, variant: &Variant, history: &[Choice]) -> Choice;
+}
+
+/// Always plays the same shape.
+pub struct Always(pub Choice);
+
+impl Strategy for Always {
+    fn name(&self, variant: &Variant) -> String {
+        format!("always {}", variant.name(self.0))
+    }
+
+    fn choose(&mut self, _variant: &Variant, _history: &[Choice]) -> Choice {
+        self.0
+    }
+}
+
+/// Plays a shape at random.
+pub struct Random<R> {
+    rng: R,
+}
+
+impl<R: Rng> Random<R> {
+    pub fn new(rng: R) -> Self {
+        Self { rng }
+    }
+}
+
+impl<R: Rng> Strategy for Random<R> {
+    fn name(&self, _variant: &Variant) -> String {
+        String::from("random")
+    }
+
+    fn choose(&mut self, variant: &Variant, _history: &[Choice]) -> Choice {
+        Choice::new(self.rng.gen_range(0..variant.len()))
+    }
+}
+
+/// Beats the shape the opponent played the most so far.
+#[derive(Default)]
+pub struct FrequencyCounter {
+    counts: HashMap<Choice, usize>,
+    // Length of the history already counted
+    seen: usize,
+}
+
+impl FrequencyCounter {
+    /// Counts the choices of `history` not seen yet, starting over when it is shorter
+    /// than the one already counted, as in a new game.
+    fn update(&mut self, history: &[Choice]) {
+        if history.len() < self.seen {
+            *self = Self::default();
+        }
+
+        for &choice in &history[self.seen..] {
+            *self.counts.entry(choice).or_insert(0) += 1;
+        }
+
+        self.seen = history.len();
+    }
+}
+
+impl Strategy for FrequencyCounter {
+    fn name(&self, _variant: &Variant) -> String {
+        String::from("frequency")
+    }
+
+    fn choose(&mut self, variant: &Variant, history: &[Choice]) -> Choice {
+        self.update(history);
+
+        // The first of the most played shapes, to be deterministic
+        let predicted = variant
+            .choices()
+            .max_by_key(|choice| {
+                (
+                    self.counts.get(choice).copied().unwrap_or(0),
+                    Reverse(*choice),
+                )
+            })
+            .unwrap_or(Choice::ROCK);
+
+        variant.choice_for(predicted, Outcome::Win)
+    }
+}
+
+/// Beats the shape the opponent played the most after its previous one, falling back
+/// on the most played shape overall while there is no such transition yet.
+#[derive(Default)]
+pub struct MarkovPredictor {
+    transitions: HashMap<(Choice, Choice), usize>,
+    frequency: FrequencyCounter,
+    // Length of the history already counted
+    seen: usize,
+}
+
+impl MarkovPredictor {
+    /// Counts the transitions of `history` not seen yet, starting over when it is
+    /// shorter than the one already counted, as in a new game.
+    fn update(&mut self, history: &[Choice]) {
+        if history.len() < self.seen {
+            self.transitions.clear();
+            self.seen = 0;
+        }
+
+        for pair in history[self.seen.saturating_sub(1)..].windows(2) {
+            *self.transitions.entry((pair[0], pair[1])).or_insert(0) += 1;
+        }
+
+        self.seen = history.len();
+    }
+}
+
+impl Strategy for MarkovPredictor {
+    fn name(&self, _variant: &Variant) -> String {
+        String::from("markov")
+    }
+
+    fn choose(&mut self, variant: &Variant, history: &[Choice]) -> Choice {
+        self.update(history);
+
+        let fallback = self.frequency.choose(variant, history);
+
+        let predicted = history.last().and_then(|&last| {
+            variant
+                .choices()
+                .map(|next| {
+                    (
+                        self.transitions.get(&(last, next)).copied().unwrap_or(0),
+                        next,
+                    )
+                })
+                .filter(|&(count, _)| count > 0)
+                .max_by_key(|&(count, next)| (count, Reverse(next)))
+                .map(|(_, next)| next)
+        });
+
+        match predicted {
+            Some(predicted) => variant.choice_for(predicted, Outcome::Win),
+            None => fallback,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adaptive_strategies() {
+        let variant = Variant::classic();
+        let history = [
+            Choice::ROCK,
+            Choice::PAPER,
+            Choice::ROCK,
+            Choice::PAPER,
+            Choice::ROCK,
+        ];
+
+        let mut frequency = FrequencyCounter::default();
+        let mut markov = MarkovPredictor::default();
+        let (mut frequency_choice, mut markov_choice) = (Choice::ROCK, Choice::ROCK);
+
+        for idx in 0..=history.len() {
+            frequency_choice = frequency.choose(&variant, &history[..idx]);
+            markov_choice = markov.choose(&variant, &history[..idx]);
+        }
+
+        // Rock is the most played, but paper always follows rock
+        assert_eq!(frequency_choice, Choice::PAPER);
+        assert_eq!(markov_choice, Choice::SCISSORS);
+    }
+
+    #[test]
+    fn adaptive_strategies_across_calls() {
+        let variant = Variant::classic();
+        let history = [Choice::ROCK, Choice::PAPER, Choice::PAPER, Choice::PAPER];
+
+        let mut frequency = FrequencyCounter::default();
+        let mut markov = MarkovPredictor::default();
+
+        // Asking twice about the same history, then skipping ahead
+        for _ in 0..2 {
+            assert_eq!(frequency.choose(&variant, &history[..1]), Choice::PAPER);
+            assert_eq!(markov.choose(&variant, &history[..1]), Choice::PAPER);
+        }
+
+        assert_eq!(frequency.choose(&variant, &history), Choice::SCISSORS);
+        assert_eq!(markov.choose(&variant, &history), Choice::SCISSORS);
+
+        // A new game only counts its own history
+        let new_game = [Choice::SCISSORS, Choice::SCISSORS];
+
+        assert_eq!(frequency.choose(&variant, &new_game[..1]), Choice::ROCK);
+        assert_eq!(markov.choose(&variant, &new_game[..1]), Choice::ROCK);
+        assert_eq!(markov.choose(&variant, &new_game), Choice::ROCK);
+    }
+}
@@ -0,0 +1,175 @@
+use common::ParseError;
+
+const SPACE: &str = "a single space";
+const END_OF_LINE: &str = "the end of the line";
+const COMMENT: char = '#';
+
+/// How strictly the lines of a strategy guide are read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Exactly two letters separated by a single space.
+    #[default]
+    Strict,
+    /// Any whitespace around the letters, lowercase letters, `#` comments and blank lines.
+    Lenient,
+}
+
+/// A letter of a line, with the slice it was read from to locate errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Letter<'a> {
+    pub letter: char,
+    pub text: &'a str,
+}
+
+impl<'a> Letter<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            letter: text.chars().next().unwrap_or_default(),
+            text,
+        }
+    }
+}
+
+/// The two letters of `line`, or `None` if the line has to be skipped.
+pub(crate) fn tokenize<'a>(
+    line: &'a str,
+    mode: ParseMode,
+    first_expected: &str,
+    second_expected: &str,
+) -> Result<Option<(Letter<'a>, Letter<'a>)>, ParseError> {
+    match mode {
+        ParseMode::Strict => tokenize_strict(line, first_expected, second_expected).map(Some),
+        ParseMode::Lenient => tokenize_lenient(line, first_expected, second_expected),
+    }
+}
+
+/// Splits the first character off `s`.
+fn split_char(s: &str) -> Option<(&str, &str)> {
+    let c = s.chars().next()?;
+
+    Some(s.split_at(c.len_utf8()))
+}
+
+fn tokenize_strict<'a>(
+    line: &'a str,
+    first_expected: &str,
+    second_expected: &str,
+) -> Result<(Letter<'a>, Letter<'a>), ParseError> {
+    let letter = |s: &'a str, expected: &str| match split_char(s) {
+        None => Err(ParseError::end_of_input(line, expected)),
+        Some((c, _)) if c.trim().is_empty() => Err(ParseError::unexpected_token(line, c, expected)),
+        Some((c, rest)) => Ok((Letter::new(c), rest)),
+    };
+
+    let (first, rest) = letter(line, first_expected)?;
+
+    let rest = match split_char(rest) {
+        None => return Err(ParseError::end_of_input(line, SPACE)),
+        Some((" ", rest)) => rest,
+        Some((c, _)) => return Err(ParseError::unexpected_token(line, c, SPACE)),
+    };
+
+    let (second, rest) = letter(rest, second_expected)?;
+
+    match rest.is_empty() {
+        true => Ok((first, second)),
+        false => Err(ParseError::unexpected_token(line, rest, END_OF_LINE)),
+    }
+}
+
+fn tokenize_lenient<'a>(
+    line: &'a str,
+    first_expected: &str,
+    second_expected: &str,
+) -> Result<Option<(Letter<'a>, Letter<'a>)>, ParseError> {
+    let content = line.split(COMMENT).next().unwrap_or(line);
+    let tokens: Vec<&str> = content.split_whitespace().collect();
+
+    let letter = |token: &'a str, expected: &str| match token.chars().count() {
+        1 => Ok(Letter {
+            letter: token.chars().next().unwrap().to_ascii_uppercase(),
+            text: token,
+        }),
+        _ => Err(ParseError::unexpected_token(line, token, expected)),
+    };
+
+    match tokens[..] {
+        [] => Ok(None),
+        [_] => Err(ParseError::end_of_input(
+            content.trim_end(),
+            second_expected,
+        )),
+        [first, second] => Ok(Some((
+            letter(first, first_expected)?,
+            letter(second, second_expected)?,
+        ))),
+        [_, _, extra, ..] => Err(ParseError::unexpected_token(line, extra, END_OF_LINE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strict_error(line: &str) -> (String, Option<String>, std::ops::Range<usize>) {
+        let err = tokenize(line, ParseMode::Strict, "first", "second").unwrap_err();
+
+        (
+            err.expected().to_string(),
+            err.token().map(str::to_string),
+            err.columns(),
+        )
+    }
+
+    #[test]
+    fn strict_accepts_well_formed_lines() {
+        let (first, second) = tokenize("A Y", ParseMode::Strict, "first", "second")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!((first.letter, second.letter), ('A', 'Y'));
+    }
+
+    #[test]
+    fn strict_rejections() {
+        assert_eq!(strict_error(""), ("first".into(), Some("".into()), 0..0));
+        assert_eq!(
+            strict_error(" A Y"),
+            ("first".into(), Some(" ".into()), 0..1)
+        );
+        assert_eq!(strict_error("A"), (SPACE.into(), Some("".into()), 1..1));
+        assert_eq!(strict_error("AXZ"), (SPACE.into(), Some("X".into()), 1..2));
+        assert_eq!(
+            strict_error("A\tX"),
+            (SPACE.into(), Some("\t".into()), 1..2)
+        );
+        assert_eq!(strict_error("A "), ("second".into(), Some("".into()), 2..2));
+        assert_eq!(
+            strict_error("A  X"),
+            ("second".into(), Some(" ".into()), 2..3)
+        );
+        assert_eq!(
+            strict_error("A X extra"),
+            (END_OF_LINE.into(), Some(" extra".into()), 3..9)
+        );
+        assert_eq!(
+            strict_error("A X\r"),
+            (END_OF_LINE.into(), Some("\r".into()), 3..4)
+        );
+    }
+
+    #[test]
+    fn lenient_tolerates_whitespace_case_and_comments() {
+        let lenient = |line| tokenize(line, ParseMode::Lenient, "first", "second");
+        let letters = |line| lenient(line).unwrap().map(|(f, s)| (f.letter, s.letter));
+
+        assert_eq!(letters("  a \t y  # first round"), Some(('A', 'Y')));
+        assert_eq!(letters("A X\r"), Some(('A', 'X')));
+        assert_eq!(letters("# only a comment"), None);
+        assert_eq!(letters("   "), None);
+
+        assert_eq!(lenient("A X extra").unwrap_err().token(), Some("extra"));
+        assert_eq!(lenient("AX Z").unwrap_err().token(), Some("AX"));
+        assert_eq!(lenient("A # Y").unwrap_err().expected(), "second");
+    }
+}
@@ -0,0 +1,275 @@
+use std::cmp::Ordering;
+
+use common::ParseError;
+
+use super::{
+    choice::Choice,
+    game::{Game, Part},
+    outcome::Outcome,
+    rules::ScoringRules,
+    tokenizer::ParseMode,
+    turn::Turn,
+    variant::Variant,
+};
+
+/// A player of a tournament, with the shapes of its strategy guide.
+#[derive(Debug, Clone)]
+pub struct Player {
+    pub name: String,
+    choices: Vec<Choice>,
+}
+
+impl Player {
+    pub fn new(name: &str, choices: Vec<Choice>) -> Self {
+        Self {
+            name: name.to_string(),
+            choices,
+        }
+    }
+
+    /// The player playing the second column of a strategy guide, read as shapes.
+    pub fn from_guide(
+        name: &str,
+        file_content: &str,
+        variant: &Variant,
+        mode: ParseMode,
+    ) -> Result<Self, ParseError> {
+        let game = Game::from_file_content_with(file_content, Part::One, variant, mode)?;
+
+        Ok(Self::new(name, game.my_choices()))
+    }
+}
+
+/// A match from the point of view of one of its players.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchResult {
+    pub score: usize,
+    pub opponent_score: usize,
+    pub rounds_won: usize,
+    pub rounds_drawn: usize,
+    pub rounds_lost: usize,
+}
+
+impl MatchResult {
+    pub fn outcome(&self) -> Outcome {
+        match self.score.cmp(&self.opponent_score) {
+            Ordering::Greater => Outcome::Win,
+            Ordering::Equal => Outcome::Draw,
+            Ordering::Less => Outcome::Lose,
+        }
+    }
+
+    fn reversed(&self) -> Self {
+        Self {
+            score: self.opponent_score,
+            opponent_score: self.score,
+            rounds_won: self.rounds_lost,
+            rounds_drawn: self.rounds_drawn,
+            rounds_lost: self.rounds_won,
+        }
+    }
+}
+
+/// The line of a player in the leaderboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub player: usize,
+    pub score: usize,
+    pub won: usize,
+    pub drawn: usize,
+    pub lost: usize,
+}
+
+/// Every player against every other one, each match lasting as many rounds as the
+/// shortest of the two guides.
+#[derive(Debug)]
+pub struct Tournament {
+    pub players: Vec<String>,
+    /// `head_to_head[a][b]` is the match of `a` against `b`, from the point of view of `a`.
+    pub head_to_head: Vec<Vec<Option<MatchResult>>>,
+    /// Players ranked by total score, then matches won, then the score of the match
+    /// between the two players, then name.
+    pub leaderboard: Vec<Standing>,
+}
+
+fn play(first: &Player, second: &Player, variant: &Variant, rules: &ScoringRules) -> MatchResult {
+    let mut result = MatchResult {
+        score: 0,
+        opponent_score: 0,
+        rounds_won: 0,
+        rounds_drawn: 0,
+        rounds_lost: 0,
+    };
+
+    for (&mine, &theirs) in first.choices.iter().zip(&second.choices) {
+        let turn = Turn::new(variant, theirs, mine);
+
+        result.score += turn.score(rules);
+        result.opponent_score += Turn::new(variant, mine, theirs).score(rules);
+
+        match turn.outcome() {
+            Outcome::Win => result.rounds_won += 1,
+            Outcome::Draw => result.rounds_drawn += 1,
+            Outcome::Lose => result.rounds_lost += 1,
+        }
+    }
+
+    result
+}
+
+impl Tournament {
+    pub fn round_robin(players: &[Player], variant: &Variant, rules: &ScoringRules) -> Self {
+        let count = players.len();
+        let mut head_to_head = vec![vec![None; count]; count];
+
+        for a in 0..count {
+            for b in (a + 1)..count {
+                let result = play(&players[a], &players[b], variant, rules);
+
+                head_to_head[b][a] = Some(result.reversed());
+                head_to_head[a][b] = Some(result);
+            }
+        }
+
+        let mut leaderboard: Vec<Standing> = head_to_head
+            .iter()
+            .enumerate()
+            .map(|(player, matches)| {
+                let matches: Vec<&MatchResult> = matches.iter().flatten().collect();
+                let count = |outcome| matches.iter().filter(|m| m.outcome() == outcome).count();
+
+                Standing {
+                    player,
+                    score: matches.iter().map(|m| m.score).sum(),
+                    won: count(Outcome::Win),
+                    drawn: count(Outcome::Draw),
+                    lost: count(Outcome::Lose),
+                }
+            })
+            .collect();
+
+        // The points scored against the other players tied on score and wins, computed
+        // before sorting so that cycles between them can't make the order inconsistent
+        let mini_league: Vec<usize> = leaderboard
+            .iter()
+            .map(|standing| {
+                leaderboard
+                    .iter()
+                    .filter(|other| (other.score, other.won) == (standing.score, standing.won))
+                    .filter_map(|other| head_to_head[standing.player][other.player].as_ref())
+                    .map(|result| result.score)
+                    .sum()
+            })
+            .collect();
+
+        leaderboard.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then(b.won.cmp(&a.won))
+                .then(mini_league[b.player].cmp(&mini_league[a.player]))
+                .then_with(|| players[a.player].name.cmp(&players[b.player].name))
+        });
+
+        Self {
+            players: players.iter().map(|player| player.name.clone()).collect(),
+            head_to_head,
+            leaderboard,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_robin() {
+        let variant = Variant::classic();
+        let rules = ScoringRules::default();
+        let rocks = Player::from_guide("rocks", "A X\nA X\n", &variant, ParseMode::Strict).unwrap();
+        let papers =
+            Player::from_guide("papers", "A Y\nA Y\nA Y\n", &variant, ParseMode::Strict).unwrap();
+        let mixed = Player::new("mixed", vec![Choice::SCISSORS, Choice::PAPER]);
+
+        let tournament = Tournament::round_robin(&[rocks, papers, mixed], &variant, &rules);
+        let papers_vs_rocks = tournament.head_to_head[1][0].as_ref().unwrap();
+
+        assert_eq!(papers_vs_rocks.score, 2 * (2 + 6));
+        assert_eq!(papers_vs_rocks.opponent_score, 2);
+        assert_eq!(papers_vs_rocks.rounds_won, 2);
+        assert_eq!(
+            tournament.head_to_head[0][1].as_ref().unwrap().rounds_lost,
+            2
+        );
+        assert!(tournament.head_to_head[2][2].is_none());
+
+        // mixed: 11 against rocks + 14 against papers, papers: 16 + 7, rocks: 2 + 8
+        let ranking: Vec<_> = tournament
+            .leaderboard
+            .iter()
+            .map(|standing| (tournament.players[standing.player].as_str(), standing.score))
+            .collect();
+
+        assert_eq!(ranking, [("mixed", 25), ("papers", 23), ("rocks", 10)]);
+    }
+
+    #[test]
+    fn ties_are_broken_by_head_to_head_then_name() {
+        let variant = Variant::classic();
+        let rules = ScoringRules::default()
+            .with_outcome(Outcome::Win, 1)
+            .with_outcome(Outcome::Draw, 1)
+            .with_outcome(Outcome::Lose, 1)
+            .with_shape(Choice::ROCK, 0)
+            .with_shape(Choice::PAPER, 0)
+            .with_shape(Choice::SCISSORS, 0);
+
+        let b = Player::new("b", vec![Choice::ROCK]);
+        let a = Player::new("a", vec![Choice::ROCK]);
+        let tournament = Tournament::round_robin(&[b, a], &variant, &rules);
+
+        assert_eq!(tournament.leaderboard[0].player, 1);
+
+        // rocks and scissors both score 15 and win a match, but rocks beats scissors
+        let rules = ScoringRules::default();
+        let players = [
+            Player::new("paper", vec![Choice::PAPER]),
+            Player::new("rocks", vec![Choice::ROCK, Choice::ROCK]),
+            Player::new("alpha", vec![Choice::SCISSORS, Choice::SCISSORS]),
+        ];
+        let tournament = Tournament::round_robin(&players, &variant, &rules);
+        let ranking: Vec<_> = tournament
+            .leaderboard
+            .iter()
+            .map(|standing| (tournament.players[standing.player].as_str(), standing.score))
+            .collect();
+
+        assert_eq!(ranking, [("rocks", 15), ("alpha", 15), ("paper", 10)]);
+    }
+
+    #[test]
+    fn cycles_are_ranked_by_name_whatever_the_order() {
+        let variant = Variant::classic();
+        let rules = ScoringRules::default()
+            .with_shape(Choice::ROCK, 0)
+            .with_shape(Choice::PAPER, 0)
+            .with_shape(Choice::SCISSORS, 0);
+        let players = [
+            Player::new("r", vec![Choice::ROCK]),
+            Player::new("p", vec![Choice::PAPER]),
+            Player::new("s", vec![Choice::SCISSORS]),
+        ];
+
+        for order in [[0, 1, 2], [2, 1, 0], [1, 2, 0]] {
+            let players: Vec<Player> = order.iter().map(|&idx| players[idx].clone()).collect();
+            let tournament = Tournament::round_robin(&players, &variant, &rules);
+            let names: Vec<_> = tournament
+                .leaderboard
+                .iter()
+                .map(|standing| tournament.players[standing.player].as_str())
+                .collect();
+
+            assert_eq!(names, ["p", "r", "s"]);
+        }
+    }
+}
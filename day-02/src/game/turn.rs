@@ -1,63 +1,107 @@
-use super::{choice::Choice, outcome::Outcome};
+use common::ParseError;
+
+use super::{
+    choice::Choice,
+    outcome::Outcome,
+    rules::ScoringRules,
+    tokenizer::{tokenize, Letter, ParseMode},
+    variant::{list_letters, Variant},
+};
 
 #[derive(Debug)]
 pub struct Turn {
     opponent_choice: Choice,
     my_choice: Choice,
+    outcome: Outcome,
 }
 
 impl Turn {
-    pub fn score(&self) -> usize {
-        let turn_score = match (self.my_choice, self.opponent_choice) {
-            (Choice::Rock, Choice::Scissors)
-            | (Choice::Paper, Choice::Rock)
-            | (Choice::Scissors, Choice::Paper) => 6,
-            (m, o) if m == o => 3,
-            (_, _) => 0,
-        };
-        let choice_score = self.my_choice.score();
+    pub fn new(variant: &Variant, opponent_choice: Choice, my_choice: Choice) -> Self {
+        Self {
+            opponent_choice,
+            my_choice,
+            outcome: variant.outcome(my_choice, opponent_choice),
+        }
+    }
 
-        choice_score + turn_score
+    pub fn opponent_choice(&self) -> Choice {
+        self.opponent_choice
     }
 
-    pub fn from_line_with_choice(line: &str) -> Result<Self, String> {
-        let (first_char, second_char) = Self::chars_from_line(line)?;
+    pub fn my_choice(&self) -> Choice {
+        self.my_choice
+    }
 
-        let opponent_choice = Choice::from_letter(&first_char)
-            .ok_or(format!("Unexpected character: '{}'", first_char))?;
-        let my_choice = Choice::from_letter(&second_char)
-            .ok_or(format!("Unexpected character: '{}'", second_char))?;
+    pub fn outcome(&self) -> Outcome {
+        self.outcome
+    }
 
-        Ok(Turn {
-            opponent_choice,
-            my_choice,
-        })
+    pub fn score(&self, rules: &ScoringRules) -> usize {
+        rules.shape_points(self.my_choice) + rules.outcome_points(self.outcome)
     }
 
-    pub fn from_line_with_outcome(line: &str) -> Result<Self, String> {
-        let (first_char, second_char) = Self::chars_from_line(line)?;
+    /// The turn of a line of the guide, or `None` if `mode` skips the line.
+    pub fn from_line_with_choice(
+        line: &str,
+        variant: &Variant,
+        mode: ParseMode,
+    ) -> Result<Option<Self>, ParseError> {
+        let my_expected = format!("my choice ({})", list_letters(variant.my_letters()));
+        let opponent_expected = Self::opponent_expected(variant);
+        let Some((opponent, mine)) = tokenize(line, mode, &opponent_expected, &my_expected)? else {
+            return Ok(None);
+        };
 
-        let opponent = Choice::from_letter(&first_char)
-            .ok_or(format!("Unexpected character: '{}'", first_char))?;
-        let instruction = Outcome::from_letter(&second_char)
-            .ok_or(format!("Unexpected character: '{}'", second_char))?;
+        let opponent_choice = Self::parse_letter(line, opponent, &opponent_expected, |c| {
+            variant.opponent_choice(c)
+        })?;
+        let my_choice = Self::parse_letter(line, mine, &my_expected, |c| variant.my_choice(c))?;
 
-        let me = match &instruction {
-            Outcome::Draw => opponent.clone(),
-            Outcome::Lose => opponent.wins_vs(),
-            Outcome::Win => opponent.loses_vs(),
+        Ok(Some(Turn::new(variant, opponent_choice, my_choice)))
+    }
+
+    /// The turn of a line of the guide, or `None` if `mode` skips the line.
+    pub fn from_line_with_outcome(
+        line: &str,
+        variant: &Variant,
+        mode: ParseMode,
+    ) -> Result<Option<Self>, ParseError> {
+        let outcome_expected = format!("the outcome ({})", list_letters(variant.outcome_letters()));
+        let opponent_expected = Self::opponent_expected(variant);
+        let Some((opponent, instruction)) =
+            tokenize(line, mode, &opponent_expected, &outcome_expected)?
+        else {
+            return Ok(None);
         };
 
-        Ok(Turn {
-            opponent_choice: opponent,
-            my_choice: me,
-        })
+        let opponent = Self::parse_letter(line, opponent, &opponent_expected, |c| {
+            variant.opponent_choice(c)
+        })?;
+        let outcome = Self::parse_letter(line, instruction, &outcome_expected, |c| {
+            variant.outcome_of_letter(c)
+        })?;
+
+        Ok(Some(Turn::new(
+            variant,
+            opponent,
+            variant.choice_for(opponent, outcome),
+        )))
     }
 
-    fn chars_from_line(line: &str) -> Result<(char, char), String> {
-        let first_char = line.chars().nth(0).ok_or("Unexpected missing line[0]")?;
-        let second_char = line.chars().nth(2).ok_or("Unexpected missing line[2]")?;
+    pub(crate) fn opponent_expected(variant: &Variant) -> String {
+        format!(
+            "the opponent choice ({})",
+            list_letters(variant.opponent_letters())
+        )
+    }
 
-        Ok((first_char, second_char))
+    fn parse_letter<T>(
+        line: &str,
+        letter: Letter,
+        expected: &str,
+        from_letter: impl Fn(char) -> Option<T>,
+    ) -> Result<T, ParseError> {
+        from_letter(letter.letter)
+            .ok_or_else(|| ParseError::unexpected_token(line, letter.text, expected))
     }
 }
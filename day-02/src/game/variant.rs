@@ -0,0 +1,251 @@
+use super::{choice::Choice, outcome::Outcome};
+
+/// The shapes of a game where each one beats the `(n - 1) / 2` shapes before it in the
+/// cycle and loses to the ones after it, which requires an odd number of shapes.
+///
+/// Every shape is mapped to a letter of the opponent and to one of mine, and the
+/// outcomes to three letters, to read the strategy guide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    shapes: Vec<String>,
+    opponent_letters: Vec<char>,
+    my_letters: Vec<char>,
+    // Letters of losing, drawing and winning
+    outcome_letters: [char; 3],
+}
+
+impl Variant {
+    /// By default the opponent's shapes are the first letters of the alphabet, mine the
+    /// last ones, and the outcomes are X, Y and Z.
+    pub fn new(shapes: &[&str]) -> Result<Self, String> {
+        let count = shapes.len();
+
+        if count < 3 || count.is_multiple_of(2) || count > 13 {
+            return Err(format!(
+                "Expected an odd number of shapes between 3 and 13, found {}",
+                count
+            ));
+        }
+
+        Ok(Self {
+            shapes: shapes.iter().map(|shape| shape.to_string()).collect(),
+            opponent_letters: ('A'..='Z').take(count).collect(),
+            my_letters: ('A'..='Z').skip(26 - count).collect(),
+            outcome_letters: ['X', 'Y', 'Z'],
+        })
+    }
+
+    /// The game of the puzzle.
+    pub fn classic() -> Self {
+        Self::new(&["rock", "paper", "scissors"]).unwrap()
+    }
+
+    /// Rock-paper-scissors-lizard-Spock, with its shapes in the order of the cycle.
+    pub fn rpsls() -> Self {
+        Self::new(&["rock", "spock", "paper", "lizard", "scissors"]).unwrap()
+    }
+
+    /// Maps the shapes, in order, to the letters of `opponent` and `mine`.
+    pub fn with_letters(mut self, opponent: &str, mine: &str) -> Result<Self, String> {
+        self.opponent_letters = self.letters(opponent)?;
+        self.my_letters = self.letters(mine)?;
+
+        Ok(self)
+    }
+
+    /// Maps losing, drawing and winning to the letters of `outcomes`.
+    pub fn with_outcome_letters(mut self, outcomes: &str) -> Result<Self, String> {
+        let letters: Vec<char> = outcomes.chars().collect();
+        Self::check_distinct(&letters)?;
+
+        self.outcome_letters = letters
+            .try_into()
+            .map_err(|_| format!("Expected 3 outcome letters, found `{}`", outcomes))?;
+
+        Ok(self)
+    }
+
+    fn letters(&self, letters: &str) -> Result<Vec<char>, String> {
+        let letters: Vec<char> = letters.chars().collect();
+
+        if letters.len() != self.len() {
+            return Err(format!(
+                "Expected {} letters, found `{}`",
+                self.len(),
+                letters.iter().collect::<String>()
+            ));
+        }
+
+        Self::check_distinct(&letters)?;
+
+        Ok(letters)
+    }
+
+    /// A letter mapped twice would make one of its shapes or outcomes unreachable.
+    fn check_distinct(letters: &[char]) -> Result<(), String> {
+        match letters
+            .iter()
+            .enumerate()
+            .find(|&(idx, letter)| letters[..idx].contains(letter))
+        {
+            Some((_, letter)) => Err(format!(
+                "Expected distinct letters, found `{}` twice in `{}`",
+                letter,
+                letters.iter().collect::<String>()
+            )),
+            None => Ok(()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    pub fn choices(&self) -> impl Iterator<Item = Choice> {
+        (0..self.len()).map(Choice::new)
+    }
+
+    pub fn name(&self, choice: Choice) -> &str {
+        &self.shapes[choice.index()]
+    }
+
+    pub fn find(&self, name: &str) -> Option<Choice> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.eq_ignore_ascii_case(name))
+            .map(Choice::new)
+    }
+
+    pub fn beats(&self, mine: Choice, opponent: Choice) -> bool {
+        let distance = (mine.index() + self.len() - opponent.index()) % self.len();
+
+        (1..=(self.len() - 1) / 2).contains(&distance)
+    }
+
+    /// The outcome of playing `mine` against `opponent`.
+    pub fn outcome(&self, mine: Choice, opponent: Choice) -> Outcome {
+        if mine == opponent {
+            Outcome::Draw
+        } else if self.beats(mine, opponent) {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    /// The shape to play against `opponent` to get `outcome`, the closest one in the
+    /// cycle when there are several.
+    pub fn choice_for(&self, opponent: Choice, outcome: Outcome) -> Choice {
+        let shift = match outcome {
+            Outcome::Draw => 0,
+            Outcome::Win => 1,
+            Outcome::Lose => self.len() - 1,
+        };
+
+        Choice::new((opponent.index() + shift) % self.len())
+    }
+
+    pub fn opponent_choice(&self, letter: char) -> Option<Choice> {
+        Self::position(&self.opponent_letters, letter).map(Choice::new)
+    }
+
+    pub fn my_choice(&self, letter: char) -> Option<Choice> {
+        Self::position(&self.my_letters, letter).map(Choice::new)
+    }
+
+    pub fn outcome_of_letter(&self, letter: char) -> Option<Outcome> {
+        match Self::position(&self.outcome_letters, letter)? {
+            0 => Some(Outcome::Lose),
+            1 => Some(Outcome::Draw),
+            _ => Some(Outcome::Win),
+        }
+    }
+
+    fn position(letters: &[char], letter: char) -> Option<usize> {
+        letters.iter().position(|&l| l == letter)
+    }
+
+    pub fn opponent_letters(&self) -> &[char] {
+        &self.opponent_letters
+    }
+
+    pub fn my_letters(&self) -> &[char] {
+        &self.my_letters
+    }
+
+    pub fn outcome_letters(&self) -> &[char] {
+        &self.outcome_letters
+    }
+}
+
+/// `A, B or C`
+pub(crate) fn list_letters(letters: &[char]) -> String {
+    match letters.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, init)) => {
+            let init: Vec<String> = init.iter().map(char::to_string).collect();
+            format!("{} or {}", init.join(", "), last)
+        }
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classic_dominance() {
+        let variant = Variant::classic();
+
+        assert!(variant.beats(Choice::PAPER, Choice::ROCK));
+        assert!(variant.beats(Choice::ROCK, Choice::SCISSORS));
+        assert!(!variant.beats(Choice::ROCK, Choice::PAPER));
+        assert_eq!(
+            variant.choice_for(Choice::ROCK, Outcome::Lose),
+            Choice::SCISSORS
+        );
+    }
+
+    #[test]
+    fn rpsls_dominance() {
+        let variant = Variant::rpsls();
+        let shape = |name| variant.find(name).unwrap();
+
+        assert!(variant.beats(shape("spock"), shape("scissors")));
+        assert!(variant.beats(shape("lizard"), shape("spock")));
+        assert!(variant.beats(shape("scissors"), shape("lizard")));
+        assert!(variant.beats(shape("paper"), shape("spock")));
+
+        for opponent in variant.choices() {
+            assert_eq!(
+                variant
+                    .choices()
+                    .filter(|&c| variant.beats(c, opponent))
+                    .count(),
+                2
+            );
+
+            for outcome in [Outcome::Lose, Outcome::Draw, Outcome::Win] {
+                let mine = variant.choice_for(opponent, outcome);
+
+                assert_eq!(variant.outcome(mine, opponent), outcome);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_variants() {
+        assert!(Variant::new(&["rock", "paper"]).is_err());
+        assert!(Variant::classic().with_letters("AB", "XYZ").is_err());
+        assert!(Variant::classic().with_letters("AAB", "XYZ").is_err());
+        assert!(Variant::classic().with_letters("ABC", "XZZ").is_err());
+        assert!(Variant::classic().with_outcome_letters("LDL").is_err());
+        assert!(Variant::classic().with_letters("ABC", "ABC").is_ok());
+        assert_eq!(list_letters(&['A', 'B', 'C']), "A, B or C");
+    }
+}
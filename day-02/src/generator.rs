@@ -0,0 +1,36 @@
+use rand::{seq::SliceRandom, Rng};
+
+use common::Generate;
+
+use crate::Game;
+
+impl Generate for Game {
+    const DEFAULT_SIZE: usize = 2500;
+
+    /// A strategy guide of `size` rounds.
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let opponent = ['A', 'B', 'C'].choose(rng).unwrap();
+                let me = ['X', 'Y', 'Z'].choose(rng).unwrap();
+
+                format!("{} {}\n", opponent, me)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn generated_input_is_valid() {
+        let input = generate::<Game>(42, Some(100));
+
+        assert_eq!(input.lines().count(), 100);
+        assert!(Game::parse(&input).is_ok());
+    }
+}
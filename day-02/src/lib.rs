@@ -0,0 +1,174 @@
+use std::io::BufRead;
+
+use common::{Example, ParseError, Solution, StreamingSolution};
+
+mod game;
+mod generator;
+
+pub use crate::game::{
+    export, Always, Choice, Format, FrequencyCounter, Game, Mapping, MappingScore, MarkovPredictor,
+    MatchResult, Optimization, Outcome, ParseMode, Part, Player, Random, Round, ScoringRules,
+    Standing, Strategy, StrategyGuide, Tournament, Variant,
+};
+
+impl Solution for Game {
+    // The second column is read as a choice in part 1 and as an outcome in part 2
+    type Input = (Game, Game);
+    type Answer = usize;
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        let game_p1 = Game::from_file_content(file_content, Part::One)?;
+        let game_p2 = Game::from_file_content(file_content, Part::Two)?;
+
+        Ok((game_p1, game_p2))
+    }
+
+    fn part_1((game, _): &Self::Input) -> Option<Self::Answer> {
+        Some(game.score(&ScoringRules::default()))
+    }
+
+    fn part_2((_, game): &Self::Input) -> Option<Self::Answer> {
+        Some(game.score(&ScoringRules::default()))
+    }
+}
+
+impl StreamingSolution for Game {
+    fn part_1_from_reader<R: BufRead>(reader: R) -> Result<Option<Self::Answer>, ParseError> {
+        Game::score_from_reader(
+            reader,
+            Part::One,
+            &Variant::classic(),
+            &ScoringRules::default(),
+        )
+        .map(Some)
+    }
+
+    fn part_2_from_reader<R: BufRead>(reader: R) -> Result<Option<Self::Answer>, ParseError> {
+        Game::score_from_reader(
+            reader,
+            Part::Two,
+            &Variant::classic(),
+            &ScoringRules::default(),
+        )
+        .map(Some)
+    }
+}
+
+/// The examples of the puzzle description.
+pub const EXAMPLES: [Example; 1] = [Example {
+    input: include_str!("../test.txt"),
+    part_1: Some("15"),
+    part_2: Some("12"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        for example in EXAMPLES {
+            example.assert_solved_by::<Game>();
+        }
+    }
+
+    #[test]
+    fn score_from_reader() {
+        let input = EXAMPLES[0].input.as_bytes();
+
+        let variant = Variant::classic();
+        let rules = ScoringRules::default();
+
+        assert_eq!(
+            Game::score_from_reader(input, Part::One, &variant, &rules),
+            Ok(15)
+        );
+        assert_eq!(
+            Game::score_from_reader(input, Part::Two, &variant, &rules),
+            Ok(12)
+        );
+    }
+
+    #[test]
+    fn custom_rules() {
+        let game = Game::from_file_content(EXAMPLES[0].input, Part::One).unwrap();
+        let rules = ScoringRules::default()
+            .with_outcome(Outcome::Win, 10)
+            .with_shape(Choice::SCISSORS, 0);
+
+        // Paper beats rock, rock loses to paper and scissors draw
+        assert_eq!(game.score(&rules), (2 + 10) + 1 + 3);
+    }
+
+    #[test]
+    fn simulate_strategies() {
+        let variant = Variant::classic();
+        let rules = ScoringRules::default();
+        let game = Game::from_file_content(EXAMPLES[0].input, Part::One).unwrap();
+
+        // Against rock, paper then scissors
+        assert_eq!(
+            game.simulate(&mut Always(Choice::ROCK), &variant, &rules),
+            (1 + 3) + 1 + (1 + 6)
+        );
+        assert_eq!(
+            game.simulate(&mut MarkovPredictor::default(), &variant, &rules),
+            game.simulate(&mut FrequencyCounter::default(), &variant, &rules)
+        );
+    }
+
+    #[test]
+    fn rpsls_strategy_guide() {
+        let variant = Variant::rpsls();
+        let rules = ScoringRules::default();
+
+        // Spock vaporizes rock, then lizard poisons Spock
+        let game =
+            Game::from_file_content_with("A W\nB Y\n", Part::One, &variant, ParseMode::Strict)
+                .unwrap();
+        assert_eq!(game.score(&rules), (2 + 6) + (4 + 6));
+
+        // Winning against scissors and drawing with paper
+        let game =
+            Game::from_file_content_with("E Z\nC Y\n", Part::Two, &variant, ParseMode::Strict)
+                .unwrap();
+        assert_eq!(game.score(&rules), (1 + 6) + (3 + 3));
+
+        let err = Game::from_file_content_with("F Z\n", Part::One, &variant, ParseMode::Strict)
+            .unwrap_err();
+        assert_eq!(err.expected(), "the opponent choice (A, B, C, D or E)");
+    }
+
+    #[test]
+    fn parse_modes() {
+        let variant = Variant::classic();
+        let rules = ScoringRules::default();
+        let parse =
+            |content, mode| Game::from_file_content_with(content, Part::One, &variant, mode);
+
+        // X, Y and Z are only letters of the second column
+        let err = parse("X Y\n", ParseMode::Strict).unwrap_err();
+        assert_eq!(err.line(), Some(1));
+        assert_eq!(err.token(), Some("X"));
+        assert_eq!(err.expected(), "the opponent choice (A, B or C)");
+
+        let err = parse("A Y\n\nB X\n", ParseMode::Strict).unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.columns(), 0..0);
+
+        let err = parse("A Y\nB  X\n", ParseMode::Strict).unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.expected(), "my choice (X, Y or Z)");
+
+        let err = parse("A Y\nB X\nC Zz\n", ParseMode::Strict).unwrap_err();
+        assert_eq!((err.line(), err.columns()), (Some(3), 3..4));
+
+        let guide = "# round one\n  a y\n\nB\tX   # lost\nc z\n";
+        let game = parse(guide, ParseMode::Lenient).unwrap();
+        assert_eq!(game.score(&rules), 15);
+        assert!(parse(guide, ParseMode::Strict).is_err());
+
+        let err = parse("a y\nB X Z\n", ParseMode::Lenient).unwrap_err();
+        assert_eq!((err.line(), err.token()), (Some(2), Some("Z")));
+    }
+}
@@ -1,14 +1,230 @@
-mod game;
-use std::fs::read_to_string;
+use std::{env, io, path::Path};
 
-use crate::game::{Game, Part};
+use common::{take_flag, take_value, InputSource};
+use day_02::{
+    export, Always, Choice, Format, FrequencyCounter, Game, Mapping, MarkovPredictor, Optimization,
+    ParseMode, Part, Player, Random, ScoringRules, Strategy, StrategyGuide, Tournament, Variant,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+const RULES_FLAG: &str = "--rules";
+const RPSLS_FLAG: &str = "--rpsls";
+const OPTIMIZE_FLAG: &str = "--optimize";
+const SIMULATE_FLAG: &str = "--simulate";
+const EXPORT_FLAG: &str = "--export";
+const PART_FLAG: &str = "--part";
+const TOURNAMENT_FLAG: &str = "--tournament";
+const LENIENT_FLAG: &str = "--lenient";
+
+// Seed of the random player, to get the same simulations on every run
+const SEED: u64 = 2022;
+
+fn print_tournament(tournament: &Tournament) {
+    println!(
+        "{:<4} {:<16} {:>8} {:>4} {:>4} {:>4}",
+        "#", "player", "score", "W", "D", "L"
+    );
+
+    for (rank, standing) in tournament.leaderboard.iter().enumerate() {
+        println!(
+            "{:<4} {:<16} {:>8} {:>4} {:>4} {:>4}",
+            rank + 1,
+            tournament.players[standing.player],
+            standing.score,
+            standing.won,
+            standing.drawn,
+            standing.lost
+        );
+    }
+
+    println!();
+    print!("{:<16}", "");
+
+    for name in &tournament.players {
+        print!(" {:>20}", name);
+    }
+
+    println!();
+
+    for (name, matches) in tournament.players.iter().zip(&tournament.head_to_head) {
+        print!("{:<16}", name);
+
+        for result in matches {
+            let cell = match result {
+                Some(result) => format!(
+                    "{}-{} {}/{}/{}",
+                    result.score,
+                    result.opponent_score,
+                    result.rounds_won,
+                    result.rounds_drawn,
+                    result.rounds_lost
+                ),
+                None => String::from("-"),
+            };
+
+            print!(" {:>20}", cell);
+        }
+
+        println!();
+    }
+}
+
+fn tournament(
+    files: &[String],
+    variant: &Variant,
+    rules: &ScoringRules,
+    mode: ParseMode,
+) -> Result<(), String> {
+    let players = files
+        .iter()
+        .map(|file| {
+            let name = Path::new(file)
+                .file_stem()
+                .map_or(file.as_str(), |stem| stem.to_str().unwrap_or(file));
+            let file_content = InputSource::from_arg(Some(file), file)
+                .read()
+                .map_err(|err| err.to_string())?;
+
+            Player::from_guide(name, &file_content, variant, mode)
+                .map_err(|err| format!("{}: {}", file, err))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    print_tournament(&Tournament::round_robin(&players, variant, rules));
+
+    Ok(())
+}
+
+fn print_simulation(game: &Game, variant: &Variant, rules: &ScoringRules) {
+    let guide_score = game.score(rules);
+
+    let mut strategies: Vec<Box<dyn Strategy>> = vec![
+        Box::new(Random::new(StdRng::seed_from_u64(SEED))),
+        Box::new(FrequencyCounter::default()),
+        Box::new(MarkovPredictor::default()),
+    ];
+    strategies.extend(
+        variant
+            .choices()
+            .map(|choice: Choice| Box::new(Always(choice)) as Box<dyn Strategy>),
+    );
+
+    println!("{:<16} {:>8}", "guide", guide_score);
+
+    for strategy in strategies.iter_mut() {
+        let score = game.simulate(strategy.as_mut(), variant, rules);
+
+        println!(
+            "{:<16} {:>8} {:>+8}",
+            strategy.name(variant),
+            score,
+            score as i64 - guide_score as i64
+        );
+    }
+}
+
+fn print_optimization(guide: &StrategyGuide, variant: &Variant, optimization: &Optimization) {
+    println!("Best possible score:  {}", optimization.best);
+    println!("Worst possible score: {}", optimization.worst);
+    println!();
+
+    for mapping in &optimization.mappings {
+        let meanings: Vec<String> = match &mapping.mapping {
+            Mapping::Choices(choices) => choices
+                .iter()
+                .map(|&choice| variant.name(choice).to_string())
+                .collect(),
+            Mapping::Outcomes(outcomes) => outcomes
+                .iter()
+                .map(|outcome| format!("{:?}", outcome).to_lowercase())
+                .collect(),
+        };
+        let letters: Vec<String> = guide
+            .letters()
+            .iter()
+            .zip(meanings)
+            .map(|(letter, meaning)| format!("{} = {}", letter, meaning))
+            .collect();
+
+        println!("{:>8}  {}", mapping.score, letters.join(", "));
+    }
+}
 
 fn main() -> Result<(), String> {
-    let filename = "input.txt";
-    let file_content = read_to_string(filename).map_err(|err| err.to_string())?;
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    let variant = match take_flag(&mut args, RPSLS_FLAG) {
+        true => Variant::rpsls(),
+        false => Variant::classic(),
+    };
+
+    let mode = match take_flag(&mut args, LENIENT_FLAG) {
+        true => ParseMode::Lenient,
+        false => ParseMode::Strict,
+    };
+
+    let optimize = take_flag(&mut args, OPTIMIZE_FLAG);
+
+    let simulate = take_flag(&mut args, SIMULATE_FLAG);
+
+    let export_format = take_value(&mut args, EXPORT_FLAG)?
+        .map(|format| format.parse::<Format>())
+        .transpose()?;
+
+    let part = match take_value(&mut args, PART_FLAG)?.as_deref() {
+        None | Some("1") => Part::One,
+        Some("2") => Part::Two,
+        Some(part) => {
+            return Err(format!(
+                "Invalid value for argument {}: {}",
+                PART_FLAG, part
+            ))
+        }
+    };
+
+    let rules = match take_value(&mut args, RULES_FLAG)? {
+        Some(path) => ScoringRules::from_file(&path, &variant)?,
+        None => ScoringRules::default(),
+    };
+
+    if take_flag(&mut args, TOURNAMENT_FLAG) {
+        return tournament(&args, &variant, &rules, mode);
+    }
+
+    let file_content = InputSource::from_arg(args.first().map(String::as_str), "input.txt")
+        .read()
+        .map_err(|err| err.to_string())?;
+
+    if optimize {
+        let guide = StrategyGuide::from_file_content(&file_content, &variant, mode)
+            .map_err(|err| err.to_string())?;
+        print_optimization(&guide, &variant, &guide.optimize(&variant, &rules));
+
+        return Ok(());
+    }
+
+    if simulate {
+        let game = Game::from_file_content_with(&file_content, Part::One, &variant, mode)
+            .map_err(|err| err.to_string())?;
+        print_simulation(&game, &variant, &rules);
+
+        return Ok(());
+    }
+
+    if let Some(format) = export_format {
+        let game = Game::from_file_content_with(&file_content, part, &variant, mode)
+            .map_err(|err| err.to_string())?;
+
+        return export(&game.rounds(&variant, &rules), format, io::stdout().lock())
+            .map_err(|err| err.to_string());
+    }
 
-    let score_p1 = Game::from_file_content(&file_content, Part::One)?.score();
-    let score_p2 = Game::from_file_content(&file_content, Part::Two)?.score();
+    let score_p1 = Game::from_file_content_with(&file_content, Part::One, &variant, mode)
+        .map_err(|err| err.to_string())?
+        .score(&rules);
+    let score_p2 = Game::from_file_content_with(&file_content, Part::Two, &variant, mode)
+        .map_err(|err| err.to_string())?
+        .score(&rules);
 
     println!("Part 1: {}", score_p1);
     println!("Part 2: {}", score_p2);
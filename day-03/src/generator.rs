@@ -0,0 +1,90 @@
+use rand::{seq::SliceRandom, Rng};
+
+use common::Generate;
+
+use crate::Rucksack;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A rucksack holding `shared` in both compartments, `badge` in one of them and
+/// otherwise only items from `pool`.
+fn rucksack<R: Rng>(rng: &mut R, shared: char, badge: char, pool: &[char]) -> String {
+    let half_len = rng.gen_range(3..=9);
+    let (pool_left, pool_right) = pool.split_at(pool.len() / 2);
+
+    let mut left = vec![shared];
+    let mut right = vec![shared];
+
+    if rng.gen() {
+        left.push(badge);
+    } else {
+        right.push(badge);
+    }
+
+    for (compartment, pool) in [(&mut left, pool_left), (&mut right, pool_right)] {
+        let missing = half_len - compartment.len();
+        compartment.extend(pool.choose_multiple(rng, missing));
+        compartment.shuffle(rng);
+    }
+
+    left.into_iter().chain(right).collect()
+}
+
+impl Generate for Rucksack {
+    const DEFAULT_SIZE: usize = 100;
+
+    /// `size` groups of three rucksacks, each with exactly one item in both compartments
+    /// and each group with exactly one badge.
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        let mut lines = vec![];
+
+        for _ in 0..size {
+            let mut items: Vec<char> = ITEMS.chars().collect();
+            items.shuffle(rng);
+
+            let (badge, items) = items.split_first().unwrap();
+
+            // Disjoint pools so the badge is the only item common to the whole group
+            for pool in items.chunks_exact(17) {
+                let (shared, pool) = pool.split_first().unwrap();
+
+                lines.push(rucksack(rng, *shared, *badge, pool));
+            }
+        }
+
+        lines.into_iter().map(|line| line + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn generated_input_is_valid() {
+        let input = generate::<Rucksack>(42, Some(20));
+        let (rucksacks, groups) = Rucksack::parse(&input).unwrap();
+
+        assert_eq!(rucksacks.len(), 60);
+
+        for rucksack in &rucksacks {
+            assert_eq!(rucksack.shared().len(), 1);
+        }
+
+        for group in &groups {
+            assert_eq!(group.badges().len(), 1);
+            assert!(group.priority() > 0);
+        }
+    }
+
+    #[test]
+    fn empty_input() {
+        let input = generate::<Rucksack>(42, Some(0));
+        let (rucksacks, groups) = Rucksack::parse(&input).unwrap();
+
+        assert_eq!(input, "");
+        assert!(rucksacks.is_empty() && groups.is_empty());
+    }
+}
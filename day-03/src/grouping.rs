@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+
+use crate::{ItemSet, Rucksack};
+
+/// The state of the search for a partition of the rucksacks.
+struct Search<'a> {
+    items: &'a [ItemSet],
+    size: usize,
+    assigned: Vec<bool>,
+    groups: Vec<Vec<usize>>,
+    // The assignments known not to lead to a partition
+    dead_ends: HashSet<Vec<bool>>,
+}
+
+impl Search<'_> {
+    /// Groups the first rucksack left with others, then the rest of the rucksacks.
+    fn partition(&mut self) -> bool {
+        let Some(first) = self.assigned.iter().position(|assigned| !assigned) else {
+            return true;
+        };
+
+        if self.dead_ends.contains(&self.assigned) {
+            return false;
+        }
+
+        let before = self.assigned.clone();
+        self.assigned[first] = true;
+
+        if self.complete(&mut vec![first], self.items[first]) {
+            return true;
+        }
+
+        self.assigned[first] = false;
+        self.dead_ends.insert(before);
+
+        false
+    }
+
+    /// Completes `group`, whose rucksacks all hold `common`, with rucksacks after its last one.
+    fn complete(&mut self, group: &mut Vec<usize>, common: ItemSet) -> bool {
+        if group.len() == self.size {
+            if common.len() != 1 {
+                return false;
+            }
+
+            self.groups.push(group.clone());
+
+            if self.partition() {
+                return true;
+            }
+
+            self.groups.pop();
+
+            return false;
+        }
+
+        let start = group.last().map_or(0, |&last| last + 1);
+
+        for idx in start..self.items.len() {
+            let common = common & self.items[idx];
+
+            if self.assigned[idx] || common.is_empty() {
+                continue;
+            }
+
+            self.assigned[idx] = true;
+            group.push(idx);
+
+            if self.complete(group, common) {
+                return true;
+            }
+
+            group.pop();
+            self.assigned[idx] = false;
+        }
+
+        false
+    }
+}
+
+/// Partitions the rucksacks, in any order, into groups of `size` sharing exactly one item
+/// type, or `None` if there is no such partition. Every group lists the indices of its
+/// rucksacks in increasing order.
+pub fn discover_groups(rucksacks: &[Rucksack], size: usize) -> Option<Vec<Vec<usize>>> {
+    if size == 0 || !rucksacks.len().is_multiple_of(size) {
+        return None;
+    }
+
+    let items: Vec<ItemSet> = rucksacks.iter().map(Rucksack::items).collect();
+    let mut search = Search {
+        items: &items,
+        size,
+        assigned: vec![false; items.len()],
+        groups: Vec::new(),
+        dead_ends: HashSet::new(),
+    };
+
+    search.partition().then_some(search.groups)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::generate;
+
+    use super::*;
+    use crate::{parse_rucksacks, ElvesGroup, EXAMPLES};
+
+    fn rucksacks(lines: &[&str]) -> Vec<Rucksack> {
+        parse_rucksacks(&lines.join("\n")).unwrap()
+    }
+
+    fn assert_partition(rucksacks: &[Rucksack], size: usize, groups: &[Vec<usize>]) {
+        let mut seen: Vec<usize> = groups.iter().flatten().copied().collect();
+        seen.sort_unstable();
+
+        assert_eq!(seen, (0..rucksacks.len()).collect::<Vec<_>>());
+
+        for group in groups {
+            let group: Vec<Rucksack> = group.iter().map(|&idx| rucksacks[idx]).collect();
+
+            assert_eq!(group.len(), size);
+            assert_eq!(
+                ElvesGroup::from_rucksacks(&group, size)
+                    .unwrap()
+                    .badges()
+                    .len(),
+                1
+            );
+        }
+    }
+
+    #[test]
+    fn example_groups() {
+        let rucksacks = parse_rucksacks(EXAMPLES[0].input).unwrap();
+        let groups = discover_groups(&rucksacks, 3).unwrap();
+
+        assert_eq!(groups, [vec![0, 1, 2], vec![3, 4, 5]]);
+        assert_partition(&rucksacks, 3, &groups);
+    }
+
+    #[test]
+    fn groups_across_lines() {
+        // a is shared by the first and third rucksacks, b by the second and fourth
+        let rucksacks = rucksacks(&["acac", "bdbd", "aeae", "bfbf"]);
+        let groups = discover_groups(&rucksacks, 2).unwrap();
+
+        assert_eq!(groups, [vec![0, 2], vec![1, 3]]);
+    }
+
+    #[test]
+    fn no_partition() {
+        // Pairs share two item types, or none
+        assert_eq!(
+            discover_groups(&rucksacks(&["abab", "abab", "cdcd", "cdcd"]), 2),
+            None
+        );
+        assert_eq!(discover_groups(&rucksacks(&["aa", "aa", "bb"]), 2), None);
+        assert_eq!(discover_groups(&rucksacks(&["aa", "aa"]), 0), None);
+    }
+
+    #[test]
+    fn generated_groups() {
+        let input = generate::<Rucksack>(7, Some(30));
+        let rucksacks = parse_rucksacks(&input).unwrap();
+        let groups = discover_groups(&rucksacks, 3).unwrap();
+
+        assert_partition(&rucksacks, 3, &groups);
+    }
+}
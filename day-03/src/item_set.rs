@@ -0,0 +1,183 @@
+use std::ops::{BitAnd, BitOr};
+
+/// The item type of a priority, from 1 for `a` to 52 for `Z`.
+fn item_of_priority(priority: u32) -> char {
+    match priority {
+        1..=26 => (b'a' + (priority - 1) as u8) as char,
+        _ => (b'A' + (priority - 27) as u8) as char,
+    }
+}
+
+/// The priority of an item type, or `None` if `item` is not in `a-zA-Z`.
+pub fn priority_of(item: char) -> Option<usize> {
+    match item {
+        'a'..='z' => Some(item as usize - 'a' as usize + 1),
+        'A'..='Z' => Some(item as usize - 'A' as usize + 27),
+        _ => None,
+    }
+}
+
+/// A set of item types, with the bit of every item type set at its priority.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The item types of `items`, ignoring any character that is not an item type.
+    pub fn from_items(items: &str) -> Self {
+        items.chars().collect()
+    }
+
+    /// Adds `item` and tells whether it is an item type.
+    pub fn insert(&mut self, item: char) -> bool {
+        match priority_of(item) {
+            Some(priority) => {
+                self.0 |= 1 << priority;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        priority_of(item).is_some_and(|priority| self.0 & (1 << priority) != 0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The item types of the set, by increasing priority.
+    pub fn iter(&self) -> Items {
+        Items(self.0)
+    }
+
+    /// The sum of the priorities of the item types of the set.
+    pub fn priority(&self) -> usize {
+        let mut bits = self.0;
+        let mut sum = 0;
+
+        while bits != 0 {
+            sum += bits.trailing_zeros() as usize;
+            bits &= bits - 1;
+        }
+
+        sum
+    }
+}
+
+impl FromIterator<char> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut set = Self::new();
+
+        for item in iter {
+            set.insert(item);
+        }
+
+        set
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(other)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+/// The item types of an [`ItemSet`], by increasing priority.
+#[derive(Debug, Clone)]
+pub struct Items(u64);
+
+impl Iterator for Items {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let priority = self.0.trailing_zeros();
+        self.0 &= self.0 - 1;
+
+        Some(item_of_priority(priority))
+    }
+}
+
+impl IntoIterator for ItemSet {
+    type Item = char;
+    type IntoIter = Items;
+
+    fn into_iter(self) -> Items {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priorities() {
+        assert_eq!(priority_of('a'), Some(1));
+        assert_eq!(priority_of('z'), Some(26));
+        assert_eq!(priority_of('A'), Some(27));
+        assert_eq!(priority_of('Z'), Some(52));
+        assert_eq!(priority_of('1'), None);
+
+        for item in ('a'..='z').chain('A'..='Z') {
+            let priority = priority_of(item).unwrap() as u32;
+            assert_eq!(item_of_priority(priority), item);
+        }
+    }
+
+    #[test]
+    fn set_operations() {
+        let left = ItemSet::from_items("vJrwpWtwJgWr");
+        let right = ItemSet::from_items("hcsFMMfFFhFp");
+
+        assert_eq!(left.len(), 8);
+        assert!(left.contains('J') && !left.contains('j') && !left.contains('-'));
+        assert_eq!((left & right).iter().collect::<String>(), "p");
+        assert_eq!((left & right).priority(), 16);
+        assert_eq!((left | right).iter().collect::<String>(), "cfghprstvwFJMW");
+        assert_eq!(
+            (left | right).priority(),
+            left.union(right)
+                .iter()
+                .map(|item| priority_of(item).unwrap())
+                .sum::<usize>()
+        );
+
+        let mut set = ItemSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert('Z'));
+        assert!(!set.insert('é'));
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), ['Z']);
+        assert_eq!(set.priority(), 52);
+    }
+}
@@ -0,0 +1,238 @@
+use std::str::FromStr;
+
+use common::{parse_lines, Example, ParseError, Solution};
+
+mod generator;
+mod grouping;
+mod item_set;
+
+pub use grouping::discover_groups;
+pub use item_set::{priority_of, ItemSet, Items};
+
+/// The number of elves of a group in the puzzle.
+pub const DEFAULT_GROUP_SIZE: usize = 3;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Rucksack {
+    left: ItemSet,
+    right: ItemSet,
+}
+
+impl Rucksack {
+    /// The item types of both compartments.
+    pub fn items(&self) -> ItemSet {
+        self.left | self.right
+    }
+
+    /// The item types found in both compartments.
+    pub fn shared(&self) -> ItemSet {
+        self.left & self.right
+    }
+
+    pub fn priority(&self) -> usize {
+        self.shared().priority()
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((idx, item)) = s.char_indices().find(|&(_, c)| priority_of(c).is_none()) {
+            let token = &s[idx..(idx + item.len_utf8())];
+
+            return Err(ParseError::unexpected_token(
+                s,
+                token,
+                "an item type (a-z or A-Z)",
+            ));
+        }
+
+        if s.is_empty() {
+            return Err(ParseError::end_of_input(s, "an item type (a-z or A-Z)"));
+        }
+
+        if !s.len().is_multiple_of(2) {
+            return Err(ParseError::unexpected_token(
+                s,
+                s,
+                "an even number of items, to fill both compartments equally",
+            ));
+        }
+
+        let (s1, s2) = s.split_at(s.len() / 2);
+
+        Ok(Self {
+            left: ItemSet::from_items(s1),
+            right: ItemSet::from_items(s2),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ElvesGroup {
+    // The item types carried by every elf of the group
+    badges: ItemSet,
+}
+
+impl ElvesGroup {
+    pub fn from_rucksacks(rucksacks: &[Rucksack], size: usize) -> Result<Self, ParseError> {
+        if rucksacks.len() != size {
+            return Err(ParseError::new(&format!(
+                "expected a group of {} rucksacks, found {}",
+                size,
+                rucksacks.len()
+            )));
+        }
+
+        let badges = rucksacks
+            .iter()
+            .map(Rucksack::items)
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default();
+
+        Ok(ElvesGroup { badges })
+    }
+
+    pub fn badges(&self) -> ItemSet {
+        self.badges
+    }
+
+    pub fn priority(&self) -> usize {
+        self.badges.priority()
+    }
+}
+
+pub fn parse_rucksacks(file_contents: &str) -> Result<Vec<Rucksack>, ParseError> {
+    parse_lines(file_contents)
+}
+
+/// Groups the `rucksacks` parsed from `file_contents` by `size` consecutive lines,
+/// failing after the last rucksack of an incomplete group.
+pub fn elves_groups(
+    file_contents: &str,
+    rucksacks: &[Rucksack],
+    size: usize,
+) -> Result<Vec<ElvesGroup>, ParseError> {
+    if size == 0 {
+        return Err(ParseError::new("expected a group size of at least 1"));
+    }
+
+    rucksacks
+        .chunks(size)
+        .enumerate()
+        .map(|(idx, group)| {
+            ElvesGroup::from_rucksacks(group, size).map_err(|_| {
+                let number = idx * size + group.len();
+                let line = file_contents.lines().nth(number - 1).unwrap_or_default();
+                let expected = format!(
+                    "{} more rucksacks for a group of {}",
+                    size - group.len(),
+                    size
+                );
+
+                ParseError::end_of_input(line, &expected).at_line(number, line)
+            })
+        })
+        .collect()
+}
+
+pub fn part_1(rucksacks: &[Rucksack]) -> usize {
+    rucksacks.iter().map(|rucksack| rucksack.priority()).sum()
+}
+
+pub fn part_2(groups: &[ElvesGroup]) -> usize {
+    groups.iter().map(|group| group.priority()).sum()
+}
+
+impl Solution for Rucksack {
+    type Input = (Vec<Rucksack>, Vec<ElvesGroup>);
+    type Answer = usize;
+
+    fn parse(file_contents: &str) -> Result<Self::Input, ParseError> {
+        let rucksacks = parse_rucksacks(file_contents)?;
+        let groups = elves_groups(file_contents, &rucksacks, DEFAULT_GROUP_SIZE)?;
+
+        Ok((rucksacks, groups))
+    }
+
+    fn part_1((rucksacks, _): &Self::Input) -> Option<Self::Answer> {
+        Some(part_1(rucksacks))
+    }
+
+    fn part_2((_, groups): &Self::Input) -> Option<Self::Answer> {
+        Some(part_2(groups))
+    }
+}
+
+/// The examples of the puzzle description.
+pub const EXAMPLES: [Example; 1] = [Example {
+    input: include_str!("../test.txt"),
+    part_1: Some("157"),
+    part_2: Some("70"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        for example in EXAMPLES {
+            example.assert_solved_by::<Rucksack>();
+        }
+    }
+
+    #[test]
+    fn invalid_rucksacks() {
+        let err = "vJrw1WtwJgWr".parse::<Rucksack>().unwrap_err();
+        assert_eq!((err.token(), err.columns()), (Some("1"), 4..5));
+
+        let err = "vJrwpWtwJgWré".parse::<Rucksack>().unwrap_err();
+        assert_eq!(err.token(), Some("é"));
+
+        let err = "vJrwpWtwJgW".parse::<Rucksack>().unwrap_err();
+        assert_eq!(err.columns(), 0..11);
+        assert!(err.expected().starts_with("an even number of items"));
+
+        let err = parse_rucksacks("vJrwpWtwJgWr\n\nPmmdzqPrV\n").unwrap_err();
+        assert_eq!((err.line(), err.columns()), (Some(2), 0..0));
+    }
+
+    #[test]
+    fn incomplete_group() {
+        let input = EXAMPLES[0]
+            .input
+            .lines()
+            .take(5)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let rucksacks = parse_rucksacks(&input).unwrap();
+        let err = elves_groups(&input, &rucksacks, DEFAULT_GROUP_SIZE).unwrap_err();
+
+        assert_eq!(err.line(), Some(5));
+        assert_eq!(err.columns(), 16..16);
+        assert_eq!(
+            err.to_string().lines().next(),
+            Some("expected 1 more rucksacks for a group of 3, found end of line")
+        );
+        assert!(err.to_string().contains("5 | ttgJtRGJQctTZtZT"));
+        assert!(Rucksack::parse(&input).is_err());
+    }
+
+    #[test]
+    fn group_sizes() {
+        let input = EXAMPLES[0].input;
+        let rucksacks = parse_rucksacks(input).unwrap();
+
+        let pairs = elves_groups(input, &rucksacks, 2).unwrap();
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs[0].badges().iter().collect::<String>(), "frsFM");
+
+        let all = elves_groups(input, &rucksacks, 6).unwrap();
+        assert!(all[0].badges().is_empty());
+
+        assert!(elves_groups(input, &rucksacks, 4).is_err());
+        assert!(elves_groups(input, &rucksacks, 0).is_err());
+    }
+}
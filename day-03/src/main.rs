@@ -1,87 +1,82 @@
-use std::{collections::HashSet, fs::read_to_string};
-
-fn priority_of_char(char: &char) -> usize {
-    let digit = *char as usize;
-    if char.is_uppercase() {
-        digit - 38
-    } else {
-        digit - 96
-    }
-}
+use std::env;
+
+use common::{take_flag, take_value, InputSource};
+use day_03::{
+    discover_groups, elves_groups, parse_rucksacks, part_1, part_2, ElvesGroup, Rucksack,
+    DEFAULT_GROUP_SIZE,
+};
+
+const GROUP_SIZE_FLAG: &str = "--group-size";
+const DISCOVER_FLAG: &str = "--discover";
+
+fn print_discovered_groups(rucksacks: &[Rucksack], size: usize) {
+    let groups = match discover_groups(rucksacks, size) {
+        Some(groups) => groups,
+        None => {
+            println!(
+                "The rucksacks cannot be split into groups of {} sharing exactly one item type",
+                size
+            );
+            return;
+        }
+    };
 
-fn build_set(str: &str) -> HashSet<char> {
-    str.chars().into_iter().collect()
-}
+    let mut total = 0;
 
-#[derive(Clone)]
-pub struct Rucksack {
-    line: String,
-}
+    for group in &groups {
+        let group_rucksacks: Vec<Rucksack> = group.iter().map(|&idx| rucksacks[idx]).collect();
+        let badges = ElvesGroup::from_rucksacks(&group_rucksacks, size)
+            .expect("A discovered group should be complete")
+            .badges();
+        let lines: Vec<String> = group.iter().map(|idx| (idx + 1).to_string()).collect();
 
-impl Rucksack {
-    pub fn from_str(str: &str) -> Self {
-        Self {
-            line: str.to_string(),
-        }
+        total += badges.priority();
+
+        println!(
+            "{} (lines {})",
+            badges.iter().collect::<String>(),
+            lines.join(", ")
+        );
     }
 
-    pub fn priority(&self) -> usize {
-        let (s1, s2) = self.line.split_at(self.line.len() / 2);
+    println!();
+    println!("Groups: {}", groups.len());
+    println!("Priority: {}", total);
+}
 
-        let left = build_set(&s1);
-        let right = build_set(&s2);
+fn main() -> Result<(), String> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
 
-        left.intersection(&right)
-            .map(|char| priority_of_char(&char))
-            .sum()
-    }
-}
+    let size = match take_value(&mut args, GROUP_SIZE_FLAG)? {
+        Some(size) => size
+            .parse::<usize>()
+            .ok()
+            .filter(|&size| size > 0)
+            .ok_or_else(|| format!("Invalid value for argument {}: {}", GROUP_SIZE_FLAG, size))?,
+        None => DEFAULT_GROUP_SIZE,
+    };
 
-pub struct ElvesGroup {
-    rucksacks: Vec<Rucksack>,
-}
+    let discover = take_flag(&mut args, DISCOVER_FLAG);
 
-impl ElvesGroup {
-    pub fn from_rucksacks(rucksacks: &[Rucksack]) -> Self {
-        ElvesGroup {
-            rucksacks: rucksacks.to_vec(),
-        }
-    }
+    let file_contents = InputSource::from_arg(args.first().map(String::as_str), "input.txt")
+        .read()
+        .map_err(|err| err.to_string())?;
 
-    pub fn priority(&self) -> usize {
-        let mut iter = self
-            .rucksacks
-            .iter()
-            .map(|rucksack| build_set(&rucksack.line));
+    let rucksacks = parse_rucksacks(&file_contents).map_err(|err| err.to_string())?;
 
-        let intersection = iter
-            .next()
-            .map(|set| iter.fold(set, |ref set1, ref set2| set1 & set2))
-            .expect("At least one set expected");
+    if discover {
+        print_discovered_groups(&rucksacks, size);
 
-        intersection.iter().map(|char| priority_of_char(char)).sum()
+        return Ok(());
     }
-}
-
-fn main() -> Result<(), String> {
-    let filename = "input.txt";
-    let file_contents = read_to_string(filename).map_err(|err| err.to_string())?;
 
-    let rucksacks: Vec<_> = file_contents
-        .lines()
-        .map(|line| Rucksack::from_str(&line))
-        .collect();
+    let groups = elves_groups(&file_contents, &rucksacks, size).map_err(|err| err.to_string())?;
 
-    let part_1: usize = rucksacks.iter().map(|rucksack| rucksack.priority()).sum();
+    let part_1 = part_1(&rucksacks);
 
     println!("Part 1: {}", part_1);
 
-    let elves_groups: Vec<_> = rucksacks
-        .chunks_exact(3)
-        .map(|rucksack_group| ElvesGroup::from_rucksacks(&rucksack_group))
-        .collect();
-
-    let part_2: usize = elves_groups.iter().map(|group| group.priority()).sum();
+    let part_2 = part_2(&groups);
 
     println!("Part 2: {}", part_2);
 
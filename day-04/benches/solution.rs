@@ -0,0 +1 @@
+common::criterion_benches!(day_04::AssignmentList);
@@ -1,5 +1,9 @@
 use core::str::FromStr;
 
+use common::ParseError;
+
+const SECTION: &str = "a section number between 0 and 255";
+
 #[derive(Debug)]
 pub struct Assignment {
     pub from: u8,
@@ -13,18 +17,27 @@ impl Assignment {
 }
 
 impl FromStr for Assignment {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let values: Vec<_> = s.split("-").take(2).collect();
+        let (from_str, to_str) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::end_of_input(s, "`-` between two sections x-y"))?;
 
-        let (from_str, to_str) = match values[..] {
-            [x, y] => (x, y),
-            _ => return Err("Expected two values x-y".to_string()),
-        };
+        if let Some(idx) = to_str.find('-') {
+            return Err(ParseError::unexpected_token(
+                s,
+                &to_str[idx..],
+                "the end of the assignment",
+            ));
+        }
 
-        let from: u8 = from_str.parse::<u8>().map_err(|e| e.to_string())?;
-        let to: u8 = to_str.parse::<u8>().map_err(|e| e.to_string())?;
+        let from: u8 = from_str
+            .parse::<u8>()
+            .map_err(|_| ParseError::unexpected_token(s, from_str, SECTION))?;
+        let to: u8 = to_str
+            .parse::<u8>()
+            .map_err(|_| ParseError::unexpected_token(s, to_str, SECTION))?;
 
         Ok(Self::new(from, to))
     }
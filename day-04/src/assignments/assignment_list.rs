@@ -1,4 +1,7 @@
 use core::str::FromStr;
+use std::io::BufRead;
+
+use common::{parse_lines, parse_lines_from, ParseError};
 
 use super::assignment_pair::AssignmentPair;
 
@@ -25,16 +28,28 @@ impl AssignmentList {
             .filter(|pair| pair.fully_overlaps())
             .count()
     }
+
+    /// Counts full overlaps and overlaps in one pass over `reader`, one line at a time.
+    pub fn counts_from_reader<R: BufRead>(reader: R) -> Result<(usize, usize), ParseError> {
+        parse_lines_from::<AssignmentPair, _>(reader).try_fold(
+            (0, 0),
+            |(full_overlaps, overlaps), pair| {
+                let pair = pair?;
+
+                Ok((
+                    full_overlaps + usize::from(pair.fully_overlaps()),
+                    overlaps + usize::from(pair.overlaps()),
+                ))
+            },
+        )
+    }
 }
 
 impl FromStr for AssignmentList {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let assignments: Vec<_> = s
-            .lines()
-            .map(|line| line.parse::<AssignmentPair>())
-            .collect::<Result<_, _>>()?;
+        let assignments: Vec<AssignmentPair> = parse_lines(s)?;
 
         Ok(AssignmentList::new(assignments))
     }
@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use common::ParseError;
+
 use super::assignment::Assignment;
 
 #[derive(Debug)]
@@ -30,21 +32,77 @@ impl AssignmentPair {
 }
 
 impl FromStr for AssignmentPair {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let values: Vec<_> = s.split(",").take(2).collect();
+        let (first_str, second_str) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::end_of_input(s, "`,` between two assignments"))?;
 
-        let (first_str, second_str) = match values[..] {
-            [f, s] => (f, s),
-            _ => return Err("".to_string()),
-        };
+        if let Some(idx) = second_str.find(',') {
+            return Err(ParseError::unexpected_token(
+                s,
+                &second_str[idx..],
+                "the end of the line",
+            ));
+        }
 
-        let first: Assignment = first_str.parse::<Assignment>().map_err(|e| e.to_string())?;
+        let first: Assignment = first_str
+            .parse::<Assignment>()
+            .map_err(|e| e.within(s, first_str))?;
         let second: Assignment = second_str
             .parse::<Assignment>()
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| e.within(s, second_str))?;
 
         Ok(Self { first, second })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn assignment() -> impl Strategy<Value = Assignment> {
+        (0..=u8::MAX)
+            .prop_flat_map(|from| (Just(from), from..=u8::MAX))
+            .prop_map(|(from, to)| Assignment::new(from, to))
+    }
+
+    fn sections(assignment: &Assignment) -> impl Iterator<Item = u8> {
+        assignment.from..=assignment.to
+    }
+
+    #[test]
+    fn trailing_tokens() {
+        let err = "2-4,6-8,1-1".parse::<AssignmentPair>().unwrap_err();
+        assert_eq!((err.token(), err.columns()), (Some(",1-1"), 7..11));
+
+        let err = "2-4-9,6-8".parse::<AssignmentPair>().unwrap_err();
+        assert_eq!((err.token(), err.columns()), (Some("-9"), 3..5));
+
+        let err = "2-4,6-8-".parse::<AssignmentPair>().unwrap_err();
+        assert_eq!((err.token(), err.columns()), (Some("-"), 7..8));
+        assert_eq!(err.expected(), "the end of the assignment");
+
+        assert!("2-4,6-8".parse::<AssignmentPair>().is_ok());
+    }
+
+    proptest! {
+        #[test]
+        fn overlaps_like_naive(first in assignment(), second in assignment()) {
+            let naive = sections(&first).any(|section| sections(&second).any(|s| s == section));
+
+            prop_assert_eq!(AssignmentPair { first, second }.overlaps(), naive);
+        }
+
+        #[test]
+        fn fully_overlaps_like_naive(first in assignment(), second in assignment()) {
+            let naive = sections(&first).all(|section| sections(&second).any(|s| s == section))
+                || sections(&second).all(|section| sections(&first).any(|s| s == section));
+
+            prop_assert_eq!(AssignmentPair { first, second }.fully_overlaps(), naive);
+        }
+    }
+}
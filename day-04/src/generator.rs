@@ -0,0 +1,41 @@
+use rand::Rng;
+
+use common::Generate;
+
+use crate::AssignmentList;
+
+fn assignment<R: Rng>(rng: &mut R) -> String {
+    let start = rng.gen_range(1..=99);
+    let end = rng.gen_range(start..=99);
+
+    format!("{}-{}", start, end)
+}
+
+impl Generate for AssignmentList {
+    const DEFAULT_SIZE: usize = 1000;
+
+    /// `size` pairs of section assignments.
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{},{}\n", assignment(rng), assignment(rng)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn generated_input_is_valid() {
+        let input = generate::<AssignmentList>(42, Some(100));
+        let list = AssignmentList::parse(&input).unwrap();
+
+        let fully_contained = AssignmentList::part_1(&list).unwrap();
+        let overlapping = AssignmentList::part_2(&list).unwrap();
+
+        assert!(fully_contained <= overlapping && overlapping <= 100);
+    }
+}
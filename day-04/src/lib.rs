@@ -0,0 +1,74 @@
+use std::io::BufRead;
+
+use common::{Example, ParseError, Solution, StreamingSolution};
+
+mod assignments;
+mod generator;
+
+pub use crate::assignments::AssignmentList;
+
+impl Solution for AssignmentList {
+    type Input = AssignmentList;
+    type Answer = usize;
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        file_content.parse()
+    }
+
+    fn part_1(assignments_list: &Self::Input) -> Option<Self::Answer> {
+        Some(assignments_list.full_overlaps_count())
+    }
+
+    fn part_2(assignments_list: &Self::Input) -> Option<Self::Answer> {
+        Some(assignments_list.overlaps_count())
+    }
+}
+
+impl StreamingSolution for AssignmentList {
+    fn part_1_from_reader<R: BufRead>(reader: R) -> Result<Option<Self::Answer>, ParseError> {
+        let (full_overlaps, _) = AssignmentList::counts_from_reader(reader)?;
+
+        Ok(Some(full_overlaps))
+    }
+
+    fn part_2_from_reader<R: BufRead>(reader: R) -> Result<Option<Self::Answer>, ParseError> {
+        let (_, overlaps) = AssignmentList::counts_from_reader(reader)?;
+
+        Ok(Some(overlaps))
+    }
+}
+
+/// The examples of the puzzle description.
+pub const EXAMPLES: [Example; 1] = [Example {
+    input: include_str!("../test.txt"),
+    part_1: Some("2"),
+    part_2: Some("4"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        for example in EXAMPLES {
+            example.assert_solved_by::<AssignmentList>();
+        }
+    }
+
+    #[test]
+    fn counts_from_reader() {
+        let counts = AssignmentList::counts_from_reader(EXAMPLES[0].input.as_bytes());
+
+        assert_eq!(counts, Ok((2, 4)));
+    }
+
+    #[test]
+    fn trailing_tokens() {
+        let err = "2-4,6-8\n2-4-9,6-8,1-1\n"
+            .parse::<AssignmentList>()
+            .unwrap_err();
+
+        assert_eq!((err.line(), err.token()), (Some(2), Some(",1-1")));
+    }
+}
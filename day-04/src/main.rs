@@ -1,12 +1,13 @@
-use std::fs::read_to_string;
-
-mod assignments;
-use crate::assignments::AssignmentList;
+use common::{InputSource, ParseError};
+use day_04::AssignmentList;
 
 fn main() -> Result<(), String> {
-    let filename = "input.txt";
-    let file_content: String = read_to_string(&filename).map_err(|err| err.to_string())?;
-    let assignments_list: AssignmentList = file_content.parse()?;
+    let file_content: String = InputSource::from_args("input.txt")
+        .read()
+        .map_err(|err| err.to_string())?;
+    let assignments_list: AssignmentList = file_content
+        .parse()
+        .map_err(|err: ParseError| err.to_string())?;
 
     let part_1 = assignments_list.full_overlaps_count();
 
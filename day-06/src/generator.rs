@@ -0,0 +1,76 @@
+use rand::{seq::SliceRandom, Rng};
+
+use common::Generate;
+
+use crate::Packet;
+
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// A datastream of `size` characters whose start-of-packet marker ends after `marker`
+/// characters, with `4 <= marker < size`.
+pub fn generate_with_marker<R: Rng>(rng: &mut R, size: usize, marker: usize) -> String {
+    assert!(
+        (4..size).contains(&marker),
+        "The marker must be in 4..{}",
+        size
+    );
+
+    let mut letters: Vec<char> = LETTERS.chars().collect();
+    letters.shuffle(rng);
+
+    // Only three different letters before the marker, so no earlier window is a marker
+    let (repeated, new) = (&letters[..3], letters[3]);
+
+    let mut signal: Vec<char> = (0..(marker - 4))
+        .map(|_| *repeated.choose(rng).unwrap())
+        .collect();
+
+    let mut last = repeated.to_vec();
+    last.shuffle(rng);
+    signal.extend(last);
+    signal.push(new);
+
+    signal.extend((marker..size).map(|_| *letters.choose(rng).unwrap()));
+
+    signal.into_iter().collect::<String>() + "\n"
+}
+
+impl Generate for Packet {
+    const DEFAULT_SIZE: usize = 4096;
+
+    /// A datastream of `size` characters, with its start-of-packet marker in the first half.
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        let size = size.max(5);
+        let marker = rng.gen_range(4..=(size / 2).max(4));
+
+        generate_with_marker(rng, size, marker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, Solution};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn generated_input_is_valid() {
+        let input = generate::<Packet>(42, Some(1000));
+        let packet = Packet::parse(&input).unwrap();
+
+        assert!(Packet::part_1(&packet).is_some());
+    }
+
+    #[test]
+    fn marker_position() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for marker in [4, 5, 50, 999] {
+            let input = generate_with_marker(&mut rng, 1000, marker);
+            let packet = Packet::parse(&input).unwrap();
+
+            assert_eq!(packet.start_of_packet(), Some(marker));
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use common::{Example, ParseError, Solution};
+
+mod generator;
+mod packet;
+
+pub use crate::generator::generate_with_marker;
+pub use crate::packet::Packet;
+
+impl Solution for Packet {
+    type Input = Packet;
+    type Answer = usize;
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        let line = file_content
+            .lines()
+            .next()
+            .ok_or("Missing first line on input")?;
+
+        Ok(Packet::new(line))
+    }
+
+    fn part_1(packet: &Self::Input) -> Option<Self::Answer> {
+        packet.start_of_packet()
+    }
+
+    fn part_2(packet: &Self::Input) -> Option<Self::Answer> {
+        packet.start_of_message()
+    }
+}
+
+/// The examples of the puzzle description.
+pub const EXAMPLES: [Example; 1] = [Example {
+    input: include_str!("../test.txt"),
+    part_1: Some("5"),
+    part_2: Some("23"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        for example in EXAMPLES {
+            example.assert_solved_by::<Packet>();
+        }
+    }
+}
@@ -1,19 +1,16 @@
 use std::error::Error;
-use std::fs::read_to_string;
 
-mod packet;
-
-use crate::packet::Packet;
+use common::InputSource;
+use day_06::Packet;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let filename = "input.txt";
-    let file_content = read_to_string(filename)?;
+    let file_content = InputSource::from_args("input.txt").read()?;
     let line = file_content
         .lines()
         .next()
         .ok_or("Missing first line on input")?;
 
-    let packet = Packet::new(&line);
+    let packet = Packet::new(line);
 
     let part_1 = packet
         .start_of_packet()
@@ -0,0 +1,134 @@
+use rand::Rng;
+
+use common::Generate;
+
+use crate::FileSystem;
+
+const DISK_SIZE: u64 = 70000000;
+const SPACE_NEEDED: u64 = 30000000;
+
+/// A four-letter name unique to `index`. Names of a fixed length keep paths unambiguous.
+fn dir_name(index: usize) -> String {
+    (0..4)
+        .map(|digit| (b'a' + (index / 26usize.pow(digit) % 26) as u8) as char)
+        .collect()
+}
+
+fn file_name<R: Rng>(rng: &mut R) -> String {
+    let len = rng.gen_range(1..=8);
+    let name: String = (0..len).map(|_| rng.gen_range('a'..='z')).collect();
+
+    match rng.gen_range(0..3) {
+        0 => name,
+        1 => name + ".txt",
+        _ => name + ".dat",
+    }
+}
+
+/// A file listed by `ls`, whose size is only known once every file has been listed.
+struct PendingFile {
+    line: usize,
+    name: String,
+    weight: u64,
+}
+
+/// Appends the `ls` of `dir` and recursively the one of its subdirectories.
+fn explore<R: Rng>(
+    rng: &mut R,
+    dir: usize,
+    children: &[Vec<usize>],
+    lines: &mut Vec<String>,
+    files: &mut Vec<PendingFile>,
+) {
+    lines.push(String::from("$ ls"));
+
+    for &child in &children[dir] {
+        lines.push(format!("dir {}", dir_name(child)));
+    }
+
+    for _ in 0..rng.gen_range(0..=4) {
+        files.push(PendingFile {
+            line: lines.len(),
+            name: file_name(rng),
+            weight: rng.gen_range(1..=300000),
+        });
+        lines.push(String::new());
+    }
+
+    for &child in &children[dir] {
+        lines.push(format!("$ cd {}", dir_name(child)));
+        explore(rng, child, children, lines, files);
+        lines.push(String::from("$ cd .."));
+    }
+}
+
+impl Generate for FileSystem {
+    const DEFAULT_SIZE: usize = 200;
+
+    /// The terminal output of exploring a random tree of `size` directories.
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        let size = size.max(1);
+        let mut children = vec![vec![]; size];
+
+        for dir in 1..size {
+            children[rng.gen_range(0..dir)].push(dir);
+        }
+
+        let mut lines = vec![String::from("$ cd /")];
+        let mut files = Vec::new();
+        explore(rng, 0, &children, &mut lines, &mut files);
+
+        // Fill the disk enough to have to free some space, but not more than it can hold
+        let target = rng.gen_range((DISK_SIZE - SPACE_NEEDED + 1)..DISK_SIZE);
+
+        // Every file gets at least a byte, and a share of the rest of the budget
+        let count = files.len() as u64;
+        let (base, budget) = match target.checked_sub(count) {
+            Some(budget) => (1, budget),
+            None => (0, target),
+        };
+        let weights: u64 = files.iter().map(|file| file.weight).sum();
+        let mut total = 0;
+
+        for file in files {
+            let size = base + file.weight * budget / weights.max(1);
+            total += size;
+
+            lines[file.line] = format!("{} {}", size, file.name);
+        }
+
+        if total < target {
+            lines.push(String::from("$ cd /"));
+            lines.push(String::from("$ ls"));
+            lines.push(format!("{} {}", target - total, file_name(rng)));
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn generated_input_is_valid() {
+        let input = generate::<FileSystem>(42, Some(50));
+        let file_system = FileSystem::parse(&input).unwrap();
+
+        assert_eq!(input.matches("$ cd ..").count(), 49);
+        assert!(FileSystem::part_1(&file_system).is_some());
+        assert!(FileSystem::part_2(&file_system).is_some());
+    }
+
+    #[test]
+    fn large_input_fits_on_the_disk() {
+        let input = generate::<FileSystem>(7, Some(20000));
+        let file_system = FileSystem::parse(&input).unwrap();
+
+        assert!(file_system.unused_space().unwrap() < SPACE_NEEDED as u32);
+        assert!(FileSystem::part_2(&file_system).is_some());
+    }
+}
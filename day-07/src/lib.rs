@@ -0,0 +1,321 @@
+use std::{collections::HashMap, io::BufRead, str::FromStr};
+
+use common::{parse_lines, parse_lines_from, Example, ParseError, Solution, StreamingSolution};
+
+mod generator;
+
+const COMMAND: &str = "`cd <dir>` or `ls`";
+const DISK_SIZE: u32 = 70000000;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct File {
+    name: String,
+    size: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Directory {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Cmd {
+    Cd(String),
+    Ls,
+}
+
+impl FromStr for Cmd {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, rest) = s
+            .split_once("$ ")
+            .ok_or_else(|| ParseError::unexpected_token(s, s, "a command starting with `$ `"))?;
+
+        // Two tokens
+        if let Some((cmd, param)) = rest.split_once(' ') {
+            return match (cmd, param) {
+                ("cd", dirname) => Ok(Self::Cd(dirname.to_owned())),
+                (_, _) => Err(ParseError::unexpected_token(s, cmd, COMMAND)),
+            };
+        // One token
+        } else if rest == "ls" {
+            return Ok(Self::Ls);
+        }
+
+        Err(ParseError::unexpected_token(s, rest, COMMAND))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CmdResult {
+    File(File),
+    Directory(Directory),
+}
+
+impl FromStr for CmdResult {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (token_1, token_2) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::unexpected_token(s, s, "`dir <name>` or `<size> <name>`"))?;
+
+        match token_1 {
+            "dir" => Ok(Self::Directory(Directory {
+                name: token_2.to_owned(),
+            })),
+            num => {
+                let file_size = num
+                    .parse::<u32>()
+                    .map_err(|_| ParseError::unexpected_token(s, num, "`dir` or a file size"))?;
+                Ok(Self::File(File {
+                    name: token_2.to_owned(),
+                    size: file_size,
+                }))
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Line {
+    Cmd(Cmd),
+    CmdResult(CmdResult),
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fst_char = s
+            .chars()
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(s, "a command or its output"))?;
+
+        if fst_char == '$' {
+            let cmd = s.parse::<Cmd>()?;
+            Ok(Self::Cmd(cmd))
+        } else {
+            let cmd = s.parse::<CmdResult>()?;
+            Ok(Self::CmdResult(cmd))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FileSystem {
+    files: HashMap<String, u32>,
+}
+
+impl FileSystem {
+    pub fn directories_smaller_than(&self, size: u32) -> Vec<(&String, &u32)> {
+        self.files.iter().filter(|(_, &v)| v <= size).collect()
+    }
+
+    pub fn directory_to_delete(&self, space_needed: u32) -> Option<u32> {
+        let unused_space = self.unused_space()?;
+        let space_to_free = space_needed.checked_sub(unused_space)?;
+
+        let &min_opt = self
+            .files
+            .values()
+            .filter(|&&size| size >= space_to_free)
+            .min()?;
+
+        Some(min_opt)
+    }
+
+    /// The space left on the disk, or `None` if the files don't fit on it.
+    pub fn unused_space(&self) -> Option<u32> {
+        DISK_SIZE.checked_sub(*self.files.get("/").unwrap_or(&0))
+    }
+
+    /// Builds the file system reading the terminal output one line at a time.
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, ParseError> {
+        Self::from_lines(parse_lines_from::<Line, _>(reader))
+    }
+
+    /// Builds the file system from parsed lines, failing on the first file that makes a
+    /// directory overflow a `u32` or the disk.
+    fn from_lines<I>(lines: I) -> Result<Self, ParseError>
+    where
+        I: IntoIterator<Item = Result<Line, ParseError>>,
+    {
+        let mut files_map: HashMap<String, u32> = HashMap::new();
+        let mut curr_path = vec![String::from("/")];
+
+        for (idx, line) in lines.into_iter().enumerate() {
+            match line? {
+                Line::Cmd(Cmd::Ls) => (),
+                Line::Cmd(Cmd::Cd(path)) if path == "/" => {
+                    curr_path = vec![String::from("/")];
+                }
+                Line::Cmd(Cmd::Cd(path)) if path == ".." => {
+                    curr_path.pop();
+                }
+                Line::Cmd(Cmd::Cd(path)) => {
+                    curr_path.push(path);
+                }
+                Line::CmdResult(CmdResult::File(file)) => {
+                    let mut path = String::new();
+
+                    for dir in &curr_path {
+                        path += dir;
+
+                        let curr_size = files_map.get(&path).unwrap_or(&0);
+                        let size = curr_size
+                            .checked_add(file.size)
+                            .filter(|&size| size <= DISK_SIZE)
+                            .ok_or_else(|| {
+                                ParseError::new(&format!(
+                                    "the files of {} don't fit on a disk of {}",
+                                    path, DISK_SIZE
+                                ))
+                                .at_line(idx + 1, &format!("{} {}", file.size, file.name))
+                            })?;
+
+                        files_map.insert(path.clone(), size);
+                    }
+                }
+                Line::CmdResult(CmdResult::Directory(_)) => (),
+            }
+        }
+
+        Ok(FileSystem { files: files_map })
+    }
+}
+
+impl TryFrom<Vec<Line>> for FileSystem {
+    type Error = ParseError;
+
+    fn try_from(lines: Vec<Line>) -> Result<Self, Self::Error> {
+        Self::from_lines(lines.into_iter().map(Ok))
+    }
+}
+
+impl Solution for FileSystem {
+    type Input = FileSystem;
+    type Answer = u32;
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<Line> = parse_lines(file_content)?;
+
+        lines.try_into()
+    }
+
+    fn part_1(file_system: &Self::Input) -> Option<Self::Answer> {
+        let size = file_system
+            .directories_smaller_than(100000)
+            .iter()
+            .map(|(_, v)| **v)
+            .sum();
+
+        Some(size)
+    }
+
+    fn part_2(file_system: &Self::Input) -> Option<Self::Answer> {
+        file_system.directory_to_delete(30000000)
+    }
+}
+
+impl StreamingSolution for FileSystem {
+    fn part_1_from_reader<R: BufRead>(reader: R) -> Result<Option<Self::Answer>, ParseError> {
+        Ok(Self::part_1(&FileSystem::from_reader(reader)?))
+    }
+
+    fn part_2_from_reader<R: BufRead>(reader: R) -> Result<Option<Self::Answer>, ParseError> {
+        Ok(Self::part_2(&FileSystem::from_reader(reader)?))
+    }
+}
+
+/// The examples of the puzzle description.
+pub const EXAMPLES: [Example; 1] = [Example {
+    input: include_str!("../test.txt"),
+    part_1: Some("95437"),
+    part_2: Some("24933642"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        for example in EXAMPLES {
+            example.assert_solved_by::<FileSystem>();
+        }
+    }
+
+    #[test]
+    fn from_reader() {
+        let file_system = FileSystem::from_reader(EXAMPLES[0].input.as_bytes()).unwrap();
+
+        assert_eq!(file_system.unused_space(), Some(21618835));
+        assert_eq!(file_system.directory_to_delete(30000000), Some(24933642));
+    }
+
+    #[test]
+    fn enough_unused_space() {
+        let file_system = FileSystem::parse("").unwrap();
+
+        assert_eq!(file_system.unused_space(), Some(70000000));
+        assert_eq!(FileSystem::part_2(&file_system), None);
+    }
+
+    #[test]
+    fn files_larger_than_the_disk() {
+        let input = "$ cd /\n$ ls\ndir a\n40000000 b.txt\n$ cd a\n$ ls\n40000000 c.txt\n";
+        let err = FileSystem::parse(input).unwrap_err();
+
+        assert_eq!(err.line(), Some(7));
+        assert!(FileSystem::from_reader(input.as_bytes()).is_err());
+
+        let input = "$ cd /\n$ ls\n4000000000 b.txt\n400000000 c.txt\n";
+        assert_eq!(FileSystem::parse(input).unwrap_err().line(), Some(3));
+    }
+
+    #[test]
+    fn parse_cd() {
+        let cmd: Result<Cmd, _> = "$ cd /".parse();
+        assert_eq!(cmd, Ok(Cmd::Cd("/".to_owned())))
+    }
+
+    #[test]
+    fn parse_ls() {
+        let cmd: Result<Cmd, _> = "$ ls".parse();
+        assert_eq!(cmd, Ok(Cmd::Ls))
+    }
+
+    #[test]
+    fn parse_file() {
+        let cmd: Result<CmdResult, _> = "14848514 b.txt".parse();
+        assert_eq!(
+            cmd,
+            Ok(CmdResult::File(File {
+                name: String::from("b.txt"),
+                size: 14848514
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_unknown_command() {
+        let cmd: Result<Cmd, _> = "$ rm -rf".parse();
+        assert_eq!(
+            cmd.map_err(|err| (err.token().map(str::to_owned), err.columns())),
+            Err((Some(String::from("rm")), 2..4))
+        )
+    }
+
+    #[test]
+    fn parse_dir() {
+        let cmd: Result<CmdResult, _> = "dir d".parse();
+        assert_eq!(
+            cmd,
+            Ok(CmdResult::Directory(Directory {
+                name: String::from("d")
+            }))
+        )
+    }
+}
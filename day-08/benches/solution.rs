@@ -0,0 +1 @@
+common::criterion_benches!(day_08::TreeGrid);
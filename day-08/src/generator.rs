@@ -0,0 +1,39 @@
+use rand::Rng;
+
+use common::Generate;
+
+use crate::TreeGrid;
+
+impl Generate for TreeGrid {
+    const DEFAULT_SIZE: usize = 99;
+
+    /// A square grid of trees with sides of `size` trees.
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        let size = size.max(3);
+
+        (0..size)
+            .map(|_| {
+                let row: String = (0..size).map(|_| rng.gen_range('0'..='9')).collect();
+                row + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn generated_input_is_valid() {
+        let input = generate::<TreeGrid>(42, Some(20));
+        let grid = TreeGrid::parse(&input).unwrap();
+
+        let visible = TreeGrid::part_1(&grid).unwrap();
+
+        assert!((76..=400).contains(&visible));
+        assert!(TreeGrid::part_2(&grid).is_some());
+    }
+}
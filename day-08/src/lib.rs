@@ -0,0 +1,292 @@
+use common::{Example, ParseError, Solution};
+use itertools::iproduct;
+use rayon::prelude::*;
+use std::str::FromStr;
+
+mod generator;
+
+/// The smallest grid with trees inside its edges.
+const MIN_SIDE: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TreeGrid {
+    trees: Vec<u8>,
+    rows: usize,
+    cols: usize,
+}
+
+impl TreeGrid {
+    /// A grid of `rows` by `cols` trees, which is only checked when parsed.
+    fn new(trees: Vec<u8>, rows: usize, cols: usize) -> Self {
+        Self { trees, rows, cols }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.trees
+            .get(x * self.cols + y)
+            .expect("Outside the grid")
+            .to_owned()
+    }
+
+    pub fn get_visible_trees(&self) -> usize {
+        // Start the count with the trees at the edges
+        let trees_at_the_edges = (self.rows * 2 + self.cols * 2) - 4;
+
+        let visible_trees_inside: usize = iproduct!(1..(self.rows - 1), 1..(self.cols - 1))
+            .par_bridge()
+            .map(|(x, y)| {
+                let tree_height = self.get(x, y);
+
+                let north: Vec<_> = self.trees_in_direction(x, y, Direction::North);
+                let south: Vec<_> = self.trees_in_direction(x, y, Direction::South);
+                let west: Vec<_> = self.trees_in_direction(x, y, Direction::West);
+                let east: Vec<_> = self.trees_in_direction(x, y, Direction::East);
+
+                let n = north.iter().filter(|&&h| h >= tree_height).count();
+                let s = south.iter().filter(|&&h| h >= tree_height).count();
+                let w = west.iter().filter(|&&h| h >= tree_height).count();
+                let e = east.iter().filter(|&&h| h >= tree_height).count();
+
+                if n == 0 || s == 0 || w == 0 || e == 0 {
+                    1
+                } else {
+                    0
+                }
+            })
+            .sum();
+
+        trees_at_the_edges + visible_trees_inside
+    }
+
+    pub fn max_scenic_score(&self) -> usize {
+        iproduct!(1..(self.rows - 1), 1..(self.cols - 1))
+            .par_bridge()
+            .map(|(x, y)| {
+                let tree_height = self.get(x, y);
+
+                let n = self.trees_in_sight(x, y, tree_height, Direction::North);
+                let s = self.trees_in_sight(x, y, tree_height, Direction::South);
+                let w = self.trees_in_sight(x, y, tree_height, Direction::West);
+                let e = self.trees_in_sight(x, y, tree_height, Direction::East);
+
+                n * s * w * e
+            })
+            .max()
+            .expect("At least one value is expected")
+    }
+
+    fn trees_in_direction(&self, x: usize, y: usize, dir: Direction) -> Vec<u8> {
+        match dir {
+            Direction::North => (0..x).map(|idx| self.get(idx, y)).rev().collect(),
+            Direction::South => ((x + 1)..self.rows).map(|idx| self.get(idx, y)).collect(),
+            Direction::West => (0..y).map(|idx| self.get(x, idx)).rev().collect(),
+            Direction::East => ((y + 1)..self.cols).map(|idx| self.get(x, idx)).collect(),
+        }
+    }
+
+    fn trees_in_sight(&self, x: usize, y: usize, curr_height: u8, dir: Direction) -> usize {
+        let trees: Vec<_> = self.trees_in_direction(x, y, dir);
+        let mut tree_count = trees.iter().take_while(|&&h| h < curr_height).count();
+
+        let count_to_the_end = match dir {
+            Direction::North => x,
+            Direction::South => self.rows - x - 1,
+            Direction::West => y,
+            Direction::East => self.cols - y - 1,
+        };
+
+        let has_reached_the_end = tree_count == count_to_the_end;
+
+        if !has_reached_the_end {
+            tree_count += 1
+        }
+
+        tree_count
+    }
+
+    fn row_from_line(line: &str, cols: usize) -> Result<Vec<u8>, ParseError> {
+        let expected = format!("a row of {} tree heights", cols);
+        let mut row = Vec::with_capacity(cols);
+
+        for (idx, c) in line.char_indices() {
+            let token = &line[idx..(idx + c.len_utf8())];
+
+            if row.len() == cols {
+                return Err(ParseError::unexpected_token(line, &line[idx..], &expected));
+            }
+
+            let height = c
+                .to_digit(10)
+                .ok_or_else(|| ParseError::unexpected_token(line, token, "a tree height (0-9)"))?;
+
+            row.push(height as u8);
+        }
+
+        if row.len() < cols {
+            return Err(ParseError::end_of_input(line, &expected));
+        }
+
+        Ok(row)
+    }
+}
+
+impl FromStr for TreeGrid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<_> = s.lines().collect();
+        let first = lines.first().copied().unwrap_or_default();
+        let cols = first.chars().count();
+
+        if cols < MIN_SIDE {
+            let expected = format!("at least {} tree heights per row", MIN_SIDE);
+
+            return Err(ParseError::end_of_input(first, &expected).at_line(1, first));
+        }
+
+        let mut trees = Vec::with_capacity(lines.len() * cols);
+
+        for (idx, line) in lines.iter().enumerate() {
+            let row = Self::row_from_line(line, cols).map_err(|err| err.at_line(idx + 1, line))?;
+            trees.extend(row);
+        }
+
+        if lines.len() < MIN_SIDE {
+            return Err(ParseError::new(&format!(
+                "expected at least {} rows of trees, found {}",
+                MIN_SIDE,
+                lines.len()
+            )));
+        }
+
+        Ok(TreeGrid::new(trees, lines.len(), cols))
+    }
+}
+
+impl Solution for TreeGrid {
+    type Input = TreeGrid;
+    type Answer = usize;
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        file_content.parse()
+    }
+
+    fn part_1(tree_grid: &Self::Input) -> Option<Self::Answer> {
+        Some(tree_grid.get_visible_trees())
+    }
+
+    fn part_2(tree_grid: &Self::Input) -> Option<Self::Answer> {
+        Some(tree_grid.max_scenic_score())
+    }
+}
+
+/// The examples of the puzzle description.
+pub const EXAMPLES: [Example; 1] = [Example {
+    input: include_str!("../test.txt"),
+    part_1: Some("21"),
+    part_2: Some("8"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const DIRECTIONS: [Direction; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ];
+
+    fn tree_grid() -> impl Strategy<Value = TreeGrid> {
+        (1..10usize, 1..10usize).prop_flat_map(|(rows, cols)| {
+            prop::collection::vec(0..10u8, rows * cols)
+                .prop_map(move |trees| TreeGrid::new(trees, rows, cols))
+        })
+    }
+
+    /// Walks one tree at a time until the edge or a tree at least as tall.
+    fn naive_trees_in_sight(grid: &TreeGrid, x: usize, y: usize, dir: Direction) -> usize {
+        let (dx, dy): (isize, isize) = match dir {
+            Direction::North => (-1, 0),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+            Direction::East => (0, 1),
+        };
+        let (mut cx, mut cy) = (x as isize, y as isize);
+        let mut count = 0;
+
+        loop {
+            cx += dx;
+            cy += dy;
+
+            if cx < 0 || cy < 0 || cx >= grid.rows as isize || cy >= grid.cols as isize {
+                return count;
+            }
+
+            count += 1;
+
+            if grid.get(cx as usize, cy as usize) >= grid.get(x, y) {
+                return count;
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn trees_in_sight_like_naive(grid in tree_grid()) {
+            for (x, y) in iproduct!(0..grid.rows, 0..grid.cols) {
+                for dir in DIRECTIONS {
+                    prop_assert_eq!(
+                        grid.trees_in_sight(x, y, grid.get(x, y), dir),
+                        naive_trees_in_sight(&grid, x, y, dir),
+                        "tree ({}, {}) looking {:?}", x, y, dir
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_grids() {
+        let err = "".parse::<TreeGrid>().unwrap_err();
+        assert_eq!((err.line(), err.token()), (Some(1), Some("")));
+
+        let err = "12\nab\n".parse::<TreeGrid>().unwrap_err();
+        assert_eq!((err.line(), err.columns()), (Some(1), 2..2));
+
+        let err = "123\n4a6\n789\n".parse::<TreeGrid>().unwrap_err();
+        assert_eq!((err.line(), err.token()), (Some(2), Some("a")));
+
+        let err = "123\n45\n789\n".parse::<TreeGrid>().unwrap_err();
+        assert_eq!((err.line(), err.columns()), (Some(2), 2..2));
+
+        let err = "123\n4567\n789\n".parse::<TreeGrid>().unwrap_err();
+        assert_eq!((err.line(), err.token()), (Some(2), Some("7")));
+
+        let err = "123\n456\n".parse::<TreeGrid>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected at least 3 rows of trees, found 2"
+        );
+
+        assert!("123\n456\n789\n".parse::<TreeGrid>().is_ok());
+    }
+
+    #[test]
+    fn examples() {
+        for example in EXAMPLES {
+            example.assert_solved_by::<TreeGrid>();
+        }
+    }
+}
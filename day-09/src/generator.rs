@@ -0,0 +1,37 @@
+use rand::{seq::SliceRandom, Rng};
+
+use common::Generate;
+
+use crate::RopeBridge;
+
+impl Generate for RopeBridge {
+    const DEFAULT_SIZE: usize = 2000;
+
+    /// `size` motions of the head of the rope.
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let direction = ['U', 'D', 'L', 'R'].choose(rng).unwrap();
+                let length = rng.gen_range(1..=20);
+
+                format!("{} {}\n", direction, length)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn generated_input_is_valid() {
+        let input = generate::<RopeBridge>(42, Some(100));
+        let instructions = RopeBridge::parse(&input).unwrap();
+
+        assert_eq!(instructions.len(), 100);
+        assert!(RopeBridge::part_1(&instructions) >= RopeBridge::part_2(&instructions));
+    }
+}
@@ -0,0 +1,275 @@
+use std::fmt::Display;
+use std::{borrow::Borrow, collections::HashSet, io::BufRead, str::FromStr};
+
+use common::{parse_lines, parse_lines_from, Example, ParseError, Solution, StreamingSolution};
+
+mod generator;
+pub mod vec2;
+
+use crate::vec2::Vec2;
+
+#[derive(Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Direction::Up => "U",
+            Direction::Down => "D",
+            Direction::Right => "R",
+            Direction::Left => "L",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Direction::Up),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(ParseError::unexpected_token(
+                s,
+                s,
+                "a direction (U, D, L or R)",
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub length: u32,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (dir_str, len_str) = s.split_once(' ').ok_or_else(|| {
+            ParseError::end_of_input(s, "a direction and a length separated by ' '")
+        })?;
+
+        let direction = dir_str
+            .parse::<Direction>()
+            .map_err(|err| err.within(s, dir_str))?;
+        let length = len_str
+            .parse::<u32>()
+            .map_err(|_| ParseError::unexpected_token(s, len_str, "a number of steps"))?;
+
+        Ok(Self { direction, length })
+    }
+}
+
+impl From<&Direction> for Vec2 {
+    fn from(instruction: &Direction) -> Self {
+        match instruction {
+            Direction::Up => Vec2::new(1, 0),
+            Direction::Down => Vec2::new(-1, 0),
+            Direction::Right => Vec2::new(0, 1),
+            Direction::Left => Vec2::new(0, -1),
+        }
+    }
+}
+
+pub struct RopeBridge {}
+
+impl RopeBridge {
+    pub fn simulate_knots<I>(instructions: I, knots_count: usize) -> u32
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Instruction>,
+    {
+        let mut knots = vec![Vec2::new(0, 0); knots_count];
+        let mut tail_positions: HashSet<Vec2> = HashSet::from([knots[0]]);
+
+        // println!("== Initial state ==\n");
+        // Self::print_state(&knots);
+
+        for instruction in instructions {
+            let instruction = instruction.borrow();
+            let vec_dir: Vec2 = (&instruction.direction).into();
+
+            // println!("== {} {} ==\n", instruction.direction, instruction.length);
+
+            for _ in 0..instruction.length {
+                knots[0] = knots[0] + vec_dir;
+
+                for idx in 1..knots_count {
+                    if !knots[idx - 1].is_near(knots[idx]) {
+                        let sum = Self::get_movement_forward(knots[idx - 1], knots[idx]);
+
+                        knots[idx] = knots[idx - 1] + sum;
+
+                        if idx == knots_count - 1 {
+                            tail_positions.insert(knots[idx]);
+                        }
+                    }
+                }
+
+                // Self::print_state(&knots)
+            }
+        }
+
+        tail_positions.len() as u32
+    }
+
+    /// Simulates the rope reading one instruction at a time from `reader`.
+    pub fn simulate_knots_from_reader<R: BufRead>(
+        reader: R,
+        knots_count: usize,
+    ) -> Result<u32, ParseError> {
+        let mut error = None;
+
+        let instructions = parse_lines_from::<Instruction, _>(reader)
+            .map_while(|instruction| instruction.map_err(|err| error = Some(err)).ok());
+        let tail_positions = Self::simulate_knots(instructions, knots_count);
+
+        error.map_or(Ok(tail_positions), Err)
+    }
+
+    #[allow(dead_code)]
+    fn print_state(knots: &[Vec2]) {
+        for i in (0..5).rev() {
+            for j in 0..6 {
+                let v = knots.iter().position(|p| p.x == i && p.y == j);
+
+                let symbol = match v {
+                    Some(idx) => idx.to_string(),
+                    None => ".".to_string(),
+                };
+
+                print!("{}", symbol);
+            }
+            println!()
+        }
+        println!()
+    }
+
+    fn get_movement_forward(from: Vec2, to: Vec2) -> Vec2 {
+        let sub = to - from;
+
+        let to_x = if from.x < to.x {
+            Vec2::new(1, 0)
+        } else {
+            Vec2::new(-1, 0)
+        };
+
+        let to_y = if from.y < to.y {
+            Vec2::new(0, 1)
+        } else {
+            Vec2::new(0, -1)
+        };
+
+        if sub.x.abs() > 1 && sub.y.abs() > 1 {
+            to_x + to_y
+        } else if sub.x.abs() > 1 {
+            to_x
+        } else {
+            to_y
+        }
+    }
+}
+
+impl Solution for RopeBridge {
+    type Input = Vec<Instruction>;
+    type Answer = u32;
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(file_content)
+    }
+
+    fn part_1(instructions: &Self::Input) -> Option<Self::Answer> {
+        Some(RopeBridge::simulate_knots(instructions, 2))
+    }
+
+    fn part_2(instructions: &Self::Input) -> Option<Self::Answer> {
+        Some(RopeBridge::simulate_knots(instructions, 10))
+    }
+}
+
+impl StreamingSolution for RopeBridge {
+    fn part_1_from_reader<R: BufRead>(reader: R) -> Result<Option<Self::Answer>, ParseError> {
+        RopeBridge::simulate_knots_from_reader(reader, 2).map(Some)
+    }
+
+    fn part_2_from_reader<R: BufRead>(reader: R) -> Result<Option<Self::Answer>, ParseError> {
+        RopeBridge::simulate_knots_from_reader(reader, 10).map(Some)
+    }
+}
+
+/// The examples of the puzzle description.
+pub const EXAMPLES: [Example; 2] = [
+    Example {
+        input: include_str!("../test.txt"),
+        part_1: Some("13"),
+        part_2: Some("1"),
+    },
+    Example {
+        input: include_str!("../test2.txt"),
+        part_1: Some("88"),
+        part_2: Some("36"),
+    },
+];
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn movement_forward_like_naive(
+            x in -100..100i32,
+            y in -100..100i32,
+            dx in -2..=2i32,
+            dy in -2..=2i32,
+        ) {
+            let head = vec2!(x, y);
+            let tail = vec2!(x + dx, y + dy);
+            prop_assume!(!head.is_near(tail));
+
+            // The tail steps once towards the head, diagonally if needed
+            let naive = tail + vec2!((x - tail.x).signum(), (y - tail.y).signum());
+            let moved = head + RopeBridge::get_movement_forward(head, tail);
+
+            prop_assert_eq!((moved.x, moved.y), (naive.x, naive.y));
+        }
+    }
+
+    #[test]
+    fn examples() {
+        for example in EXAMPLES {
+            example.assert_solved_by::<RopeBridge>();
+        }
+    }
+
+    #[test]
+    fn simulate_knots_from_reader() {
+        let input = EXAMPLES[1].input.as_bytes();
+
+        assert_eq!(RopeBridge::simulate_knots_from_reader(input, 10), Ok(36));
+        assert_eq!(
+            RopeBridge::simulate_knots_from_reader("R 4\nX 1\n".as_bytes(), 2)
+                .map_err(|err| err.line()),
+            Err(Some(2))
+        );
+    }
+
+    #[test]
+    fn is_near() {
+        assert!(vec2!(1, 4).is_near(vec2!(0, 3)));
+    }
+}
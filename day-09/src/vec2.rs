@@ -34,7 +34,7 @@ impl Vec2 {
     pub fn is_near(&self, v: Self) -> bool {
         let s = *self - v;
 
-        return s.x.abs() <= 1 && s.y.abs() <= 1;
+        s.x.abs() <= 1 && s.y.abs() <= 1
     }
 }
 